@@ -0,0 +1,197 @@
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use crate::default_button::DefaultButton;
+use crate::dialog::WinDialog;
+use crate::dynamic::DynDialog;
+use crate::icon::Icon;
+use crate::modality::Modality;
+use crate::style::{
+    AbortRetryIgnore, CancelRetryContinue, DialogStyle, OkCancel, Ok_, RetryCancel, YesNo,
+    YesNoCancel,
+};
+
+/// Names one of the styles of [crate::style], for choosing the style of a [DialogConfig]
+/// from data.
+///
+/// With the `serde` feature enabled, a style is serialized as the name of its variant and
+/// deserialized with its [FromStr] implementation, so unknown names are reported as
+/// [crate::Error::UnknownName].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String")
+)]
+pub enum StyleKind {
+    #[default]
+    /// The [Ok_] style. [StyleKind::Ok] is the default.
+    Ok,
+    /// The [OkCancel] style.
+    OkCancel,
+    /// The [AbortRetryIgnore] style.
+    AbortRetryIgnore,
+    /// The [YesNoCancel] style.
+    YesNoCancel,
+    /// The [YesNo] style.
+    YesNo,
+    /// The [RetryCancel] style.
+    RetryCancel,
+    /// The [CancelRetryContinue] style.
+    CancelRetryContinue,
+}
+
+/// Parses the name of a style, ignoring case: the name of a [StyleKind] variant, such as
+/// `"YesNo"`, or the name of the style type, such as `"Ok_"`. Fails with
+/// [crate::Error::UnknownName] for any other name.
+///
+/// ```
+/// use win_dialog::StyleKind;
+///
+/// assert_eq!("yesno".parse::<StyleKind>().unwrap(), StyleKind::YesNo);
+/// assert_eq!("Ok_".parse::<StyleKind>().unwrap(), StyleKind::Ok);
+/// assert!("YesNoMaybe".parse::<StyleKind>().is_err());
+/// ```
+impl FromStr for StyleKind {
+    type Err = crate::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "ok" | "ok_" => Ok(StyleKind::Ok),
+            "okcancel" => Ok(StyleKind::OkCancel),
+            "abortretryignore" => Ok(StyleKind::AbortRetryIgnore),
+            "yesnocancel" => Ok(StyleKind::YesNoCancel),
+            "yesno" => Ok(StyleKind::YesNo),
+            "retrycancel" => Ok(StyleKind::RetryCancel),
+            "cancelretrycontinue" => Ok(StyleKind::CancelRetryContinue),
+            _ => Err(crate::Error::UnknownName {
+                kind: "style",
+                name: name.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for StyleKind {
+    type Error = crate::Error;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+/// The definition of a message box as plain data, for dialogs that are described in a
+/// configuration file rather than in code. Converts into a [DynDialog] with [TryFrom],
+/// which starts from [WinDialog::new], so the [crate::GlobalDefaults] apply, and checks
+/// the result with [WinDialog::validate].
+///
+/// With the `serde` feature enabled, the config can be deserialized, for example from JSON
+/// or TOML. Omitted fields take their default value, and unknown fields are rejected.
+///
+/// ```rust
+/// use win_dialog::{DialogConfig, DynDialog, Icon, StyleKind};
+///
+/// let config = DialogConfig {
+///     title: Some("Updater".to_string()),
+///     content: "Install the update now?".to_string(),
+///     style: StyleKind::YesNo,
+///     icon: Some(Icon::Information),
+///     ..Default::default()
+/// };
+///
+/// let dialog = DynDialog::try_from(config.clone()).unwrap();
+/// assert_eq!(dialog.describe().title.as_deref(), Some("Updater"));
+/// assert_eq!(dialog.describe().buttons, ["Yes", "No"]);
+///
+/// let help = DialogConfig {
+///     make_service_notification: true,
+///     help_button: true,
+///     ..config
+/// };
+/// assert!(DynDialog::try_from(help).is_err());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct DialogConfig {
+    /// The header of the message box, see [WinDialog::with_header].
+    pub title: Option<String>,
+    /// The text of the message box.
+    pub content: String,
+    /// Text displayed above the content, see [WinDialog::with_instruction].
+    pub instruction: Option<String>,
+    /// The buttons of the message box.
+    pub style: StyleKind,
+    /// The icon of the message box, see [WinDialog::with_icon].
+    pub icon: Option<Icon>,
+    /// The modality of the message box, see [WinDialog::set_modality].
+    pub modality: Modality,
+    /// The button selected by default, see [WinDialog::set_default_button].
+    pub default_button: DefaultButton,
+    /// Display a help button, see [WinDialog::with_help_button].
+    pub help_button: bool,
+    /// Make the message box the foreground window, see [WinDialog::set_foreground].
+    pub foreground: bool,
+    /// Create the message box as a topmost window, see [WinDialog::set_topmost].
+    pub topmost: bool,
+    /// Display the message box as a service notification, see
+    /// [WinDialog::make_service_notification].
+    pub make_service_notification: bool,
+}
+
+impl TryFrom<DialogConfig> for DynDialog {
+    type Error = crate::Error;
+
+    /// Builds the dialog described by the config. Fails with
+    /// [crate::Error::InvalidConfiguration] if the options conflict, see
+    /// [WinDialog::validate].
+    fn try_from(config: DialogConfig) -> Result<Self, Self::Error> {
+        let mut dialog = WinDialog::new(config.content)
+            .set_modality(config.modality)
+            .set_default_button(config.default_button);
+        if let Some(title) = config.title {
+            dialog = dialog.with_header(title);
+        }
+        if let Some(instruction) = config.instruction {
+            dialog = dialog.with_instruction(instruction);
+        }
+        if let Some(icon) = config.icon {
+            dialog = dialog.with_icon(icon);
+        }
+        if config.help_button {
+            dialog = dialog.with_help_button();
+        }
+        if config.foreground {
+            dialog = dialog.set_foreground();
+        }
+        if config.topmost {
+            dialog = dialog.set_topmost();
+        }
+        if config.make_service_notification {
+            dialog = dialog.make_service_notification();
+        }
+
+        match config.style {
+            StyleKind::Ok => erase(dialog, Ok_),
+            StyleKind::OkCancel => erase(dialog, OkCancel),
+            StyleKind::AbortRetryIgnore => erase(dialog, AbortRetryIgnore),
+            StyleKind::YesNoCancel => erase(dialog, YesNoCancel),
+            StyleKind::YesNo => erase(dialog, YesNo),
+            StyleKind::RetryCancel => erase(dialog, RetryCancel),
+            StyleKind::CancelRetryContinue => erase(dialog, CancelRetryContinue),
+        }
+    }
+}
+
+/// Gives the dialog the style, checks it, and erases the style.
+fn erase<T>(dialog: WinDialog<Ok_>, style: T) -> crate::Result<DynDialog>
+where
+    T: DialogStyle + Debug + Send + Sync + 'static,
+{
+    let dialog = dialog.with_style(style);
+    dialog.validate()?;
+    Ok(dialog.into())
+}
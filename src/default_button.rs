@@ -0,0 +1,171 @@
+use windows::Win32::UI::WindowsAndMessaging::{
+    MB_DEFBUTTON1, MB_DEFBUTTON2, MB_DEFBUTTON3, MB_DEFBUTTON4, MESSAGEBOX_STYLE,
+};
+
+/// Indicates which button of the message box is selected by default, counted from the
+/// left. If the user presses 'enter' without doing anything else, this is the button
+/// that will be pressed. When a help button is shown, it is always the last button.
+///
+/// Without a help button, making it the default selects the first button instead:
+///
+/// ```rust
+/// use win_dialog::style::{
+///     AbortRetryIgnore, CancelRetryContinue, OkCancel, RetryCancel, YesNo, YesNoCancel,
+/// };
+/// use win_dialog::{WinDialog, WinDialogWithParent};
+/// use windows::Win32::Foundation::HWND;
+/// use windows::Win32::UI::WindowsAndMessaging::{
+///     MB_DEFBUTTON1, MB_DEFBUTTON3, MB_DEFBUTTON4, MB_DEFMASK, MESSAGEBOX_STYLE,
+/// };
+///
+/// macro_rules! assert_help_default {
+///     ($($style:ident => $help:ident),* $(,)?) => {$(
+///         let default = |dialog: WinDialogWithParent<$style>| -> MESSAGEBOX_STYLE {
+///             dialog.set_default_help().style_flags() & MB_DEFMASK
+///         };
+///         let dialog = WinDialog::new("Content").set_parent_window(HWND(1)).with_style($style);
+///         assert_eq!(default(dialog.clone()), MB_DEFBUTTON1, stringify!($style));
+///         assert_eq!(default(dialog.with_help_button()), $help, stringify!($style));
+///     )*};
+/// }
+///
+/// assert_help_default! {
+///     OkCancel => MB_DEFBUTTON3,
+///     AbortRetryIgnore => MB_DEFBUTTON4,
+///     YesNoCancel => MB_DEFBUTTON4,
+///     YesNo => MB_DEFBUTTON3,
+///     RetryCancel => MB_DEFBUTTON3,
+///     CancelRetryContinue => MB_DEFBUTTON4,
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DefaultButton {
+    #[default]
+    /// The first button is the default. [DefaultButton::First] is the default.
+    First,
+    /// The second button is the default.
+    Second,
+    /// The third button is the default.
+    Third,
+    /// The fourth button is the default.
+    Fourth,
+}
+
+impl From<DefaultButton> for MESSAGEBOX_STYLE {
+    fn from(value: DefaultButton) -> Self {
+        match value {
+            DefaultButton::First => MB_DEFBUTTON1,
+            DefaultButton::Second => MB_DEFBUTTON2,
+            DefaultButton::Third => MB_DEFBUTTON3,
+            DefaultButton::Fourth => MB_DEFBUTTON4,
+        }
+    }
+}
+
+impl DefaultButton {
+    /// The one-based position of the button, counted from the left.
+    pub(crate) fn position(self) -> usize {
+        match self {
+            DefaultButton::First => 1,
+            DefaultButton::Second => 2,
+            DefaultButton::Third => 3,
+            DefaultButton::Fourth => 4,
+        }
+    }
+
+    /// The button at the given one-based position, if a message box can have that many buttons.
+    pub(crate) fn from_position(position: usize) -> Option<Self> {
+        match position {
+            1 => Some(DefaultButton::First),
+            2 => Some(DefaultButton::Second),
+            3 => Some(DefaultButton::Third),
+            4 => Some(DefaultButton::Fourth),
+            _ => None,
+        }
+    }
+}
+
+/// The flag that makes the button at the one-based `target` position the default, for a
+/// message box displaying `style_button_count` buttons and, if `has_help` is set, the help
+/// button after them. Windows ignores a default button that is not displayed, so the first
+/// button is selected explicitly in that case. This is the only place where positions are
+/// turned into flags, so the help button is accounted for consistently.
+pub(crate) fn default_button_index(
+    style_button_count: usize,
+    has_help: bool,
+    target: usize,
+) -> MESSAGEBOX_STYLE {
+    let displayed = style_button_count + usize::from(has_help);
+    match DefaultButton::from_position(target) {
+        Some(button) if target <= displayed => button.into(),
+        _ => MB_DEFBUTTON1,
+    }
+}
+
+/// Selects the default button by what it does rather than where it is. `R` is the response
+/// type of the dialog's style, so only buttons the style actually displays can be chosen.
+/// Any response converts into a [DefaultChoice], so responses may be passed directly to
+/// [WinDialog::set_default](crate::WinDialog::set_default).
+///
+/// The help button is always placed after the buttons of the style, so its position
+/// depends on the style:
+///
+/// ```rust
+/// use win_dialog::style::{
+///     AbortRetryIgnoreResponse, CancelRetryContinueResponse, OkCancelResponse, OkResponse,
+///     RetryCancelResponse, YesNoCancelResponse, YesNoResponse,
+/// };
+/// use win_dialog::{
+///     AbortRetryIgnore, CancelRetryContinue, DefaultChoice, OkCancel, Ok_, RetryCancel,
+///     WinDialog, YesNo, YesNoCancel,
+/// };
+/// use windows::Win32::UI::WindowsAndMessaging::{
+///     MB_DEFBUTTON1, MB_DEFBUTTON2, MB_DEFBUTTON3, MB_DEFBUTTON4, MB_DEFMASK,
+/// };
+///
+/// macro_rules! assert_defaults {
+///     ($($style:ident: $response:expr => $button:ident, help => $help:ident;)*) => {$(
+///         for has_help in [false, true] {
+///             let dialog = WinDialog::new("Continue?").with_style($style);
+///             let dialog = match has_help {
+///                 true => dialog.with_help_button(),
+///                 false => dialog,
+///             };
+///             let default = |choice| dialog.clone().set_default(choice).style_flags() & MB_DEFMASK;
+///             let help = match has_help {
+///                 true => $help,
+///                 false => MB_DEFBUTTON1,
+///             };
+///             let description = format!("{} with help: {has_help}", stringify!($style));
+///             assert_eq!(default(DefaultChoice::Response($response)), $button, "{description}");
+///             assert_eq!(default(DefaultChoice::Help), help, "{description}");
+///         }
+///     )*};
+/// }
+///
+/// // The response is the last button of the style, which is only the first one for Ok_.
+/// assert_defaults! {
+///     Ok_: OkResponse::Ok => MB_DEFBUTTON1, help => MB_DEFBUTTON2;
+///     OkCancel: OkCancelResponse::Cancel => MB_DEFBUTTON2, help => MB_DEFBUTTON3;
+///     AbortRetryIgnore: AbortRetryIgnoreResponse::Ignore => MB_DEFBUTTON3, help => MB_DEFBUTTON4;
+///     YesNoCancel: YesNoCancelResponse::Cancel => MB_DEFBUTTON3, help => MB_DEFBUTTON4;
+///     YesNo: YesNoResponse::No => MB_DEFBUTTON2, help => MB_DEFBUTTON3;
+///     RetryCancel: RetryCancelResponse::Cancel => MB_DEFBUTTON2, help => MB_DEFBUTTON3;
+///     CancelRetryContinue: CancelRetryContinueResponse::Continue => MB_DEFBUTTON3, help => MB_DEFBUTTON4;
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DefaultChoice<R> {
+    /// The button that produces this response is the default.
+    Response(R),
+    /// The help button is the default. If the help button is not shown, the first button
+    /// is the default instead.
+    Help,
+}
+
+impl<R> From<R> for DefaultChoice<R> {
+    fn from(value: R) -> Self {
+        DefaultChoice::Response(value)
+    }
+}
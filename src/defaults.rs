@@ -0,0 +1,55 @@
+use std::sync::OnceLock;
+
+use crate::icon::Icon;
+
+/// The defaults registered with [set_global_defaults].
+static GLOBAL_DEFAULTS: OnceLock<GlobalDefaults> = OnceLock::new();
+
+/// Options that every dialog created with [crate::WinDialog::new] starts out with. See
+/// [set_global_defaults].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GlobalDefaults {
+    /// Make dialogs the foreground window, see [crate::WinDialog::set_foreground].
+    pub foreground: bool,
+    /// Create dialogs as topmost windows, see [crate::WinDialog::set_topmost].
+    pub topmost: bool,
+    /// The icon of dialogs that do not set one, see [crate::WinDialog::with_icon] and
+    /// [crate::WinDialog::without_icon].
+    pub default_icon: Option<Icon>,
+}
+
+/// Register options that every dialog of the application starts out with, instead of
+/// repeating the same builder calls for each dialog. [crate::WinDialog::new], and the
+/// constructors built on it, apply the defaults, and the builder methods still override
+/// them for a single dialog, for example [crate::WinDialog::unset_foreground],
+/// [crate::WinDialog::with_icon], or [crate::WinDialog::without_icon] to display no icon at
+/// all. Dialogs that were created before the call are not affected, and neither are
+/// dialogs created with [crate::WinDialog::default].
+///
+/// The defaults can only be set once, typically at startup. Later calls leave them
+/// unchanged and return the rejected defaults. Setting and reading them is thread safe,
+/// so dialogs may be created on any thread, but a dialog created concurrently with the
+/// first call may or may not see the defaults.
+///
+/// ```rust
+/// use win_dialog::{set_global_defaults, GlobalDefaults, Icon, WinDialog};
+///
+/// set_global_defaults(GlobalDefaults {
+///     foreground: true,
+///     default_icon: Some(Icon::Information),
+///     ..Default::default()
+/// })
+/// .unwrap();
+///
+/// assert_eq!(WinDialog::new("Saved").describe().icon, Some(Icon::Information));
+/// assert_eq!(WinDialog::error("Failed").describe().icon, Some(Icon::Stop));
+/// assert_eq!(WinDialog::new("Saved").without_icon().describe().icon, None);
+/// ```
+pub fn set_global_defaults(defaults: GlobalDefaults) -> Result<(), GlobalDefaults> {
+    GLOBAL_DEFAULTS.set(defaults)
+}
+
+/// Returns the registered defaults, or the options of a plain dialog if none were set.
+pub(crate) fn global_defaults() -> GlobalDefaults {
+    GLOBAL_DEFAULTS.get().copied().unwrap_or_default()
+}
@@ -0,0 +1,62 @@
+use crate::{Icon, Modality};
+use std::time::Duration;
+
+/// The fully resolved parameters of a message box, as they would be passed to Windows by
+/// [crate::WinDialog::show], captured without showing anything. Returned by
+/// [crate::WinDialog::describe].
+///
+/// Together with the `mock` feature this allows snapshot tests of the code that builds
+/// dialogs. With the `serde` feature enabled, the descriptor can be serialized into a
+/// golden file.
+///
+/// ```rust
+/// use win_dialog::{style::YesNoCancel, Icon, WinDialog};
+///
+/// let descriptor = WinDialog::new("Save changes to the document?")
+///     .with_title("Editor")
+///     .with_style(YesNoCancel)
+///     .with_icon(Icon::Warning)
+///     .set_default_no()
+///     .describe();
+///
+/// assert_eq!(descriptor.title.as_deref(), Some("Editor"));
+/// assert_eq!(descriptor.buttons, ["Yes", "No", "Cancel"]);
+/// assert_eq!(descriptor.default_button, "No");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DialogDescriptor {
+    /// The text of the title bar. `None` results in the default title.
+    pub title: Option<String>,
+
+    /// The heading placed above the content, if any.
+    pub instruction: Option<String>,
+
+    /// The body text of the message box, without the instruction.
+    pub content: String,
+
+    /// The name of the style, see [crate::DialogStyle::NAME].
+    pub style: &'static str,
+
+    /// The icon displayed, if any.
+    pub icon: Option<Icon>,
+
+    /// The modality of the message box.
+    pub modality: Modality,
+
+    /// The labels of the buttons displayed, in order, including the help button.
+    pub buttons: Vec<&'static str>,
+
+    /// The label of the button that is selected by default. This is the first button
+    /// when the configured default button is not displayed.
+    pub default_button: &'static str,
+
+    /// The raw flags passed to the MessageBox api, see [crate::WinDialog::style_flags].
+    pub style_flags: u32,
+
+    /// How long the message box waits for input before closing itself, if set.
+    pub duration: Option<Duration>,
+
+    /// Whether the message box has a parent window.
+    pub has_parent: bool,
+}
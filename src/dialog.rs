@@ -1,24 +1,46 @@
-use std::ffi::CString;
-use windows::core::PCSTR;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::core::PCWSTR;
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::Foundation::GetLastError;
 use windows::Win32::Foundation::HWND;
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::UI::WindowsAndMessaging::MessageBoxW;
 use windows::Win32::UI::WindowsAndMessaging::{
-    MessageBoxA, MB_DEFAULT_DESKTOP_ONLY, MB_DEFBUTTON1, MB_DEFBUTTON2, MB_DEFBUTTON3,
-    MB_DEFBUTTON4, MB_HELP, MB_RIGHT, MB_RTLREADING, MB_SERVICE_NOTIFICATION, MB_SETFOREGROUND,
-    MB_TOPMOST, MESSAGEBOX_STYLE,
+    IDABORT, IDCANCEL, IDCONTINUE, IDIGNORE, IDNO, IDOK, IDRETRY, IDTRYAGAIN, IDYES,
+    MB_DEFAULT_DESKTOP_ONLY, MB_DEFMASK, MB_HELP, MB_ICONMASK, MB_MODEMASK, MB_RIGHT,
+    MB_RTLREADING, MB_SERVICE_NOTIFICATION, MB_SETFOREGROUND, MB_TOPMOST, MB_TYPEMASK,
+    MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
 };
 
+use crate::default_button::{default_button_index, DefaultButton, DefaultChoice};
+use crate::defaults::global_defaults;
+use crate::describe::DialogDescriptor;
+use crate::dynamic::{DynDialog, DynStyle};
+use crate::error::RetryError;
+use crate::handle::{CloseOnDrop, DialogHandle, DialogWindow};
+use crate::hook::on_dialog_created;
 use crate::icon::Icon;
+use crate::language::{is_rtl_language, ThreadLanguage};
 use crate::modality::Modality;
-use crate::style::DialogStyle;
+use crate::sound::Sound;
+use crate::style::{unrecognized, DialogStyle};
 use crate::style::{
-    AbortRetryIgnore, CancelRetryContinue, OkCancel, RetryCancel, YesNo, YesNoCancel,
+    AbortRetryIgnore, AbortRetryIgnoreResponse, CancelRetryContinue, CancelRetryContinueResponse,
+    OkCancel, OkCancelResponse, Ok_, RetryCancel, RetryCancelResponse, YesNo, YesNoCancel,
+    YesNoCancelResponse, YesNoResponse,
 };
+use crate::task_dialog::{CustomButton, WinTaskDialog};
 
 /// Alias used to indicate the common return type for the two [WinDialog] and [WinDialogWithParent].
 type ShowReturn<T> = crate::Result<<T as DialogStyle>::Return>;
 
-/// A builder struct used for configuring a [MessageBox](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-messageboxa).
-/// Uses the MessageBoxA function under the hood.
+/// A builder struct used for configuring a [MessageBox](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-messageboxw).
+/// Uses the MessageBoxW function under the hood.
 ///
 /// From the official Windows documentation:
 ///
@@ -26,9 +48,11 @@ type ShowReturn<T> = crate::Result<<T as DialogStyle>::Return>;
 /// and a brief application-specific message, such as status or error information.
 /// The message box returns an integer value that indicates which button the user clicked."
 ///
-/// The default button const generic
-#[derive(Debug, Default, PartialEq)]
-pub struct WinDialog<T = OkCancel, const DEFAULT_BUTTON: i32 = 0>
+/// [WinDialog::default] creates a dialog with empty content and every option unset. Unlike
+/// [WinDialog::new], it does not apply the defaults registered with
+/// [crate::set_global_defaults].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WinDialog<T = Ok_>
 where
     T: DialogStyle,
 {
@@ -36,6 +60,9 @@ where
     /// rendering a default header. Passing an empty string results in no header.
     header: Option<String>,
 
+    /// A heading placed above the body text. See [WinDialog::with_instruction].
+    instruction: Option<String>,
+
     /// The body text of the message box.
     content: String,
 
@@ -49,7 +76,7 @@ where
 
     /// Indicates which button is by default selected (i.e. if the user pressed 'enter'
     /// without doing anything else, which button would be pressed)
-    default_button: MESSAGEBOX_STYLE,
+    default_button: DefaultButton,
 
     /// Indicates the modality of the box.
     modality: Modality,
@@ -71,60 +98,385 @@ where
 
     /// The caller is a service notifying the user of an event.
     is_service_notification: bool,
+
+    /// How long the message box waits for user input before closing itself.
+    /// See [WinDialog::with_duration].
+    duration: Option<Duration>,
+
+    /// Strip nul characters from the text instead of failing. See [WinDialog::with_lossy_content].
+    lossy_content: bool,
+
+    /// Indicates whether this message box should display a help button.
+    show_help_button: bool,
+
+    /// The length of content above which the dialog is not displayed as a message box.
+    /// See [WinDialog::with_content_limit].
+    content_limit: Option<usize>,
+
+    /// Display content above the limit in a task dialog instead of failing. See
+    /// [WinDialog::allow_long_content].
+    long_content: bool,
+
+    /// A sound file that is played while the dialog is displayed. See [WinDialog::with_sound].
+    sound: Option<Sound>,
+
+    /// Display the icon without its system sound, in a task dialog. See
+    /// [WinDialog::with_alert].
+    silent: bool,
+
+    /// The language of the standard buttons. See [WinDialog::with_language].
+    language: Option<u16>,
+
+    /// Additional flags passed to the MessageBox api. See [WinDialog::with_raw_flags].
+    raw_flags: MESSAGEBOX_STYLE,
+
+    /// The number of characters after which content lines are broken. See
+    /// [WinDialog::wrap_at].
+    wrap_columns: Option<usize>,
 }
 
+/// The length of content, in UTF-16 code units, above which a message box may cut the
+/// content off. See [WinDialog::with_content_limit].
+const DEFAULT_CONTENT_LIMIT: usize = 2000;
+
 impl WinDialog {
     /// Create a new dialog with content only. This will wait indefinitely
     /// for user input and will have a default windows title. It will display
     /// a simple popover with only an Ok button and a close icon in the top right.
+    /// Use [WinDialog::with_style] to offer the user a choice.
+    ///
+    /// The dialog starts out with the defaults registered with
+    /// [crate::set_global_defaults], if any.
     pub fn new(content: impl Into<String>) -> Self {
+        let defaults = global_defaults();
         Self {
             content: content.into(),
-            style: OkCancel,
+            style: Ok_,
+            icon: defaults.default_icon,
+            foreground: defaults.foreground,
+            topmost: defaults.topmost,
             ..Default::default()
         }
     }
+
+    /// Create a dialog that only informs the user, with a single 'ok' button. This is the
+    /// same dialog as [WinDialog::new], named for the common case of showing a message
+    /// that needs no decision.
+    ///
+    /// ```rust
+    /// use win_dialog::{Ok_, WinDialog};
+    ///
+    /// let dialog: WinDialog<Ok_> = WinDialog::message("The backup has finished.");
+    /// assert_eq!(dialog.describe().buttons, ["OK"]);
+    /// ```
+    pub fn message(content: impl Into<String>) -> WinDialog<Ok_> {
+        WinDialog::new(content)
+    }
+
+    /// Create a dialog reporting an error, with the [Icon::Stop] icon and an 'ok' button.
+    pub fn error(content: impl Into<String>) -> WinDialog<Ok_> {
+        WinDialog::new(content).with_icon(Icon::Stop)
+    }
+
+    /// Create a dialog reporting an error, like [WinDialog::error], with the error's message
+    /// as the content. The chain of underlying errors, if any, is listed below the message:
+    ///
+    /// ```text
+    /// Could not save the document
+    ///
+    /// Caused by:
+    ///     Access is denied. (os error 5)
+    /// ```
+    pub fn from_error(error: &dyn std::error::Error) -> WinDialog<Ok_> {
+        let mut content = error.to_string();
+        let mut source = error.source();
+
+        if source.is_some() {
+            content.push_str("\n\nCaused by:");
+        }
+        while let Some(cause) = source {
+            content.push_str("\n    ");
+            content.push_str(&cause.to_string());
+            source = cause.source();
+        }
+
+        WinDialog::error(content)
+    }
+
+    /// Create a dialog reporting a failed process, like [WinDialog::error], from its exit
+    /// status. Returns `None` if the process succeeded. Otherwise the content is the given
+    /// context followed by the exit code, or on Unix the signal that terminated the process:
+    ///
+    /// ```text
+    /// Could not build the project
+    ///
+    /// The process exited with code 101.
+    /// ```
+    pub fn from_exit_status(
+        status: std::process::ExitStatus,
+        context: &str,
+    ) -> Option<WinDialog<Ok_>> {
+        if status.success() {
+            return None;
+        }
+
+        let detail = match status.code() {
+            Some(code) => format!("The process exited with code {code}."),
+            None => exit_signal_detail(status),
+        };
+
+        Some(WinDialog::error(format!("{context}\n\n{detail}")))
+    }
+
+    /// Create a dialog warning about an action, with the [Icon::Warning] icon and 'ok' and
+    /// 'cancel' buttons.
+    pub fn warning(content: impl Into<String>) -> WinDialog<OkCancel> {
+        WinDialog::new(content)
+            .with_style(OkCancel)
+            .with_icon(Icon::Warning)
+    }
+
+    /// Create a dialog asking the user to confirm an action, with 'yes' and 'no' buttons.
+    /// No icon is displayed, since the question mark icon is deprecated.
+    pub fn confirm(content: impl Into<String>) -> WinDialog<YesNo> {
+        WinDialog::new(content).with_style(YesNo)
+    }
+
+    /// Create a dialog informing the user, with the [Icon::Information] icon and an 'ok'
+    /// button.
+    pub fn info(content: impl Into<String>) -> WinDialog<Ok_> {
+        WinDialog::new(content).with_icon(Icon::Information)
+    }
 }
 
 impl<T> WinDialog<T>
 where
     T: Default + DialogStyle,
 {
-    /// Sets custom content for the message box header. Passing nothing results in
-    /// rendering a default header. Passing an empty string results in no header.
+    /// Sets custom content for the message box header, which is the text of its title bar.
+    /// Passing nothing results in rendering a default header. Passing an empty string
+    /// results in no header.
+    ///
+    /// The header is encoded and kept alive for as long as the message box is displayed, so
+    /// headers of any length are shown in full:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "record")]
+    /// # {
+    /// use win_dialog::{set_mock_response, take_recorded_dialogs, WinDialog};
+    /// use windows::Win32::UI::WindowsAndMessaging::IDOK;
+    ///
+    /// let header = "Synchronizing the shared project folder ".repeat(20);
+    /// set_mock_response(IDOK.0);
+    /// WinDialog::new("Done").with_header(&header).show().unwrap();
+    /// assert_eq!(take_recorded_dialogs()[0].title.as_deref(), Some(header.as_str()));
+    /// # }
+    /// ```
     pub fn with_header(mut self, header: impl Into<String>) -> Self {
         self.header = Some(header.into());
         self
     }
 
-    /// Set an [Icon] for the dialog box.
+    /// Sets the text of the title bar. An alias for [WinDialog::with_header], matching
+    /// [crate::WinTaskDialog::with_title].
+    pub fn with_title(self, title: impl Into<String>) -> Self {
+        self.with_header(title)
+    }
+
+    /// Set a main instruction, such as the question the dialog asks. Message boxes have no
+    /// separate heading, so the instruction is placed above the content, separated from it
+    /// by a blank line. Use [crate::WinTaskDialog::with_instruction] for a real heading.
+    pub fn with_instruction(mut self, instruction: impl Into<String>) -> Self {
+        self.instruction = Some(instruction.into());
+        self
+    }
+
+    /// Replaces the body text of the message box that was passed to [WinDialog::new].
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = content.into();
+        self
+    }
+
+    /// Set an [Icon] for the dialog box. Windows plays the system sound associated with the
+    /// icon when the message box appears, and the MessageBox api offers no way to prevent
+    /// it. Use [WinDialog::with_alert] for an icon without a sound.
+    ///
+    /// Message boxes cannot display [Icon::Shield], so the information icon is displayed
+    /// instead. Use a [crate::WinTaskDialog] for prompts that require administrator
+    /// privileges.
     pub fn with_icon(mut self, icon: impl Into<Icon>) -> Self {
         self.icon = Some(icon.into());
         self
     }
 
+    /// Display the dialog without an icon. This removes an icon set with
+    /// [WinDialog::with_icon], as well as the default icon registered with
+    /// [crate::set_global_defaults].
+    pub fn without_icon(mut self) -> Self {
+        self.icon = None;
+        self
+    }
+
+    /// Set an [Icon] for the dialog box, and choose whether the system sound associated
+    /// with it is played when the dialog appears. With `play_sound` set, this is the same
+    /// as [WinDialog::with_icon].
+    ///
+    /// The MessageBox api always plays the sound of its icon, so without `play_sound` the
+    /// dialog is displayed as a task dialog instead, with the same title, instruction,
+    /// content, icon, buttons and owner window, and returns the same responses. As with
+    /// [WinDialog::allow_long_content], options that only apply to message boxes, such as
+    /// the help button and the modality, are ignored. A task dialog cannot time out, so
+    /// showing it fails with [crate::Error::InvalidConfiguration] if
+    /// [WinDialog::with_duration] was called. The task dialog requires version 6 of the
+    /// Common Controls library, see [WinTaskDialog].
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use win_dialog::{Icon, WinDialog};
+    ///
+    /// let dialog = WinDialog::new("The export has finished.").with_alert(Icon::Information, false);
+    /// assert!(dialog.validate().is_ok());
+    /// assert!(dialog.with_duration(Duration::from_secs(5)).validate().is_err());
+    /// ```
+    pub fn with_alert(mut self, icon: impl Into<Icon>, play_sound: bool) -> Self {
+        self.icon = Some(icon.into());
+        self.silent = !play_sound;
+        self
+    }
+
+    /// Adds a Help button to the message box. When the user clicks the Help button
+    /// or presses F1, the system sends a [WM_HELP](https://learn.microsoft.com/en-us/windows/win32/shell/wm-help)
+    /// message to the owner window.
+    ///
+    /// Without an owner window, set with [WinDialog::set_parent_window], the button is still
+    /// displayed, but clicking it has no effect since there is no window to notify.
+    pub fn with_help_button(mut self) -> Self {
+        self.show_help_button = true;
+        self
+    }
+
     /// A handle to the owner window of the message box to be created.
     /// If you don't call this method and provide a handle to the owner window,
     /// the Message Box will have no parent window.
     ///
-    /// Attaching a parent window will allow you to add an extra 'help' button
-    /// to the message box. See [WinDialogWithParent::with_help_button] for more
+    /// Attaching a parent window allows the parent to be notified when the user
+    /// clicks the 'help' button. See [WinDialog::with_help_button] for more
     /// information.
-    pub fn set_parent_window(mut self, handle: impl Into<HWND>) -> WinDialogWithParent<T> {
-        self.is_service_notification = false;
+    ///
+    /// A message box cannot be both a service notification and owned by a window. See
+    /// [WinDialog::make_service_notification] for how that conflict is reported.
+    pub fn set_parent_window(self, handle: impl Into<HWND>) -> WinDialogWithParent<T> {
         WinDialogWithParent {
             inner: self,
             window_handle: handle.into(),
-            ..Default::default()
         }
     }
 
-    /// Indicate the modality of the dialog box. See [Modality] for the options.
+    /// An alias for [WinDialog::set_parent_window], named consistently with the other
+    /// `with_` builder methods.
+    pub fn with_handle(self, handle: impl Into<HWND>) -> WinDialogWithParent<T> {
+        self.set_parent_window(handle)
+    }
+
+    /// Same as [WinDialog::set_parent_window], but takes the window handle as a raw
+    /// integer, as it is commonly passed across FFI boundaries.
+    pub fn set_parent_window_raw(self, handle: isize) -> WinDialogWithParent<T> {
+        self.set_parent_window(HWND(handle))
+    }
+
+    /// Same as [WinDialog::set_parent_window], but takes the window handle in the form
+    /// provided by windowing libraries such as winit through the
+    /// [raw-window-handle](https://crates.io/crates/raw-window-handle) crate. Fails with
+    /// [crate::Error::UnsupportedWindowHandle] if the handle is not a Win32 handle.
+    #[cfg(feature = "raw-window-handle")]
+    pub fn set_parent_raw_window_handle(
+        self,
+        handle: raw_window_handle::RawWindowHandle,
+    ) -> crate::Result<WinDialogWithParent<T>> {
+        match handle {
+            raw_window_handle::RawWindowHandle::Win32(handle) => {
+                Ok(self.set_parent_window_raw(handle.hwnd.get()))
+            }
+            _ => Err(crate::Error::UnsupportedWindowHandle),
+        }
+    }
+
+    /// Indicate the modality of the dialog box. See [Modality] for the options. The
+    /// modality flag is the same whether or not a parent window is attached; Windows
+    /// decides how it applies based on the presence of the parent.
+    ///
+    /// ```
+    /// use win_dialog::{Modality, WinDialog};
+    /// use windows::Win32::Foundation::HWND;
+    /// use windows::Win32::UI::WindowsAndMessaging::{
+    ///     MB_APPLMODAL, MB_MODEMASK, MB_SYSTEMMODAL, MB_TASKMODAL,
+    /// };
+    ///
+    /// for modality in Modality::all() {
+    ///     let expected = match modality {
+    ///         Modality::App => MB_APPLMODAL,
+    ///         Modality::Task => MB_TASKMODAL,
+    ///         Modality::System => MB_SYSTEMMODAL,
+    ///     };
+    ///
+    ///     let dialog = WinDialog::new("Saving failed").set_modality(modality);
+    ///     assert_eq!(dialog.style_flags() & MB_MODEMASK, expected, "{modality:?}");
+    ///
+    ///     let dialog = dialog.set_parent_window(HWND(1));
+    ///     assert_eq!(dialog.style_flags() & MB_MODEMASK, expected, "{modality:?} with parent");
+    ///
+    ///     let dialog = WinDialog::new("Saving failed")
+    ///         .set_parent_window(HWND(1))
+    ///         .set_modality(modality);
+    ///     assert_eq!(dialog.style_flags() & MB_MODEMASK, expected, "{modality:?} set on parent");
+    /// }
+    /// ```
     pub fn set_modality(mut self, modality: Modality) -> Self {
         self.modality = modality;
         self
     }
 
+    /// Indicate which button is selected by default, by its position in the message box.
+    /// See [DefaultButton] for the options. The style specific helpers, such as
+    /// `set_default_cancel`, are shortcuts for this method.
+    pub fn set_default_button(mut self, button: DefaultButton) -> Self {
+        self.default_button = button;
+        self
+    }
+
+    /// Indicate which button is selected by default, by the response it produces rather
+    /// than its position. The position is worked out from the style, and the help button,
+    /// when shown, is always placed after the style's buttons. If the chosen button is not
+    /// displayed, for example [DefaultChoice::Help] without [WinDialog::with_help_button],
+    /// the first button is the default.
+    ///
+    /// ```
+    /// use win_dialog::{style::{YesNoCancel, YesNoCancelResponse}, DefaultChoice, WinDialog};
+    /// use windows::Win32::UI::WindowsAndMessaging::{
+    ///     MB_DEFBUTTON1, MB_DEFBUTTON3, MB_DEFBUTTON4, MB_DEFMASK,
+    /// };
+    ///
+    /// let dialog = || WinDialog::new("Save changes?").with_style(YesNoCancel);
+    ///
+    /// let cancel = dialog().set_default(YesNoCancelResponse::Cancel);
+    /// assert_eq!(cancel.style_flags() & MB_DEFMASK, MB_DEFBUTTON3);
+    ///
+    /// let help = dialog().set_default(DefaultChoice::Help);
+    /// assert_eq!(help.style_flags() & MB_DEFMASK, MB_DEFBUTTON1);
+    /// let help = help.with_help_button();
+    /// assert_eq!(help.style_flags() & MB_DEFMASK, MB_DEFBUTTON4);
+    /// ```
+    pub fn set_default(self, which: impl Into<DefaultChoice<T::Return>>) -> Self {
+        let button = match which.into() {
+            DefaultChoice::Response(response) => T::button_for(response),
+            DefaultChoice::Help => {
+                DefaultButton::from_position(T::BUTTON_COUNT + 1).unwrap_or_default()
+            }
+        };
+        self.set_default_button(button)
+    }
+
     /// Same as desktop of the interactive window station. For more information, see
     /// [Window Stations](https://learn.microsoft.com/en-us/windows/win32/winstation/window-stations).
     /// If the current input desktop is not the default desktop, the Message Box does not return until the
@@ -134,212 +486,1376 @@ where
         self
     }
 
-    /// Set the text to right-justify style.
+    /// Undo [WinDialog::set_default_desktop_only].
+    pub fn unset_default_desktop_only(mut self) -> Self {
+        self.default_desktop_only = false;
+        self
+    }
+
+    /// Set the text to right-justify style. Only the text is affected: the buttons of a
+    /// message box follow its layout and cannot be aligned separately. Use
+    /// [WinTaskDialog::align_buttons_right] to control the alignment of the buttons.
     pub fn set_right_justify(mut self) -> Self {
         self.right_justify_text = true;
         self
     }
 
+    /// Undo [WinDialog::set_right_justify], aligning the text to the left again.
+    pub fn unset_right_justify(mut self) -> Self {
+        self.right_justify_text = false;
+        self
+    }
+
     /// Displays message and caption text using right-to-left reading order on Hebrew and Arabic systems.
     pub fn set_right_to_left_reading(mut self) -> Self {
         self.right_to_left_reading = true;
         self
     }
 
-    /// The message box becomes the foreground window. Internally, the system calls the
-    /// [SetForegroundWindow](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setforegroundwindow) function for the message box.
-    pub fn set_foreground(mut self) -> Self {
-        self.foreground = true;
-        self
-    }
+    /// Undo [WinDialog::set_right_to_left_reading], reading the text from left to right again.
+    pub fn unset_right_to_left_reading(mut self) -> Self {
+        self.right_to_left_reading = false;
+        self
+    }
+
+    /// Lay the message box out for a right-to-left locale. Enables both
+    /// [WinDialog::set_right_to_left_reading] and [WinDialog::set_right_justify]: the
+    /// reading order alone reverses the text but leaves it aligned to the left, which
+    /// looks wrong to readers of right-to-left languages.
+    pub fn set_rtl_locale(self) -> Self {
+        self.set_right_to_left_reading().set_right_justify()
+    }
+
+    /// Display the standard buttons in the given language, regardless of the language of
+    /// the system, for example to check a German or Arabic layout on an English machine.
+    /// The language is a Windows language identifier, such as `0x0407` for German, and
+    /// must be installed. Otherwise showing the dialog fails with
+    /// [crate::Error::SystemError].
+    ///
+    /// Only the text that Windows provides, such as the labels of the standard buttons, is
+    /// affected, not the content, title or instruction set on the dialog. For Hebrew and
+    /// Arabic, [WinDialog::set_rtl_locale] is applied as well, so that the text is laid out
+    /// from right to left. The language is switched with
+    /// [SetThreadUILanguage](https://learn.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-setthreaduilanguage)
+    /// for the thread that shows the dialog, and switched back when the dialog closes.
+    pub fn with_language(mut self, language: u16) -> Self {
+        self.language = Some(language);
+        match is_rtl_language(language) {
+            true => self.set_rtl_locale(),
+            false => self,
+        }
+    }
+
+    /// Pass additional `MB_*` flags to the MessageBox api, for flags that this crate does
+    /// not model, such as ones added to Windows after its release. The flags are combined
+    /// with the ones computed from the other options, and calling this again adds to the
+    /// flags passed before.
+    ///
+    /// Where the flags conflict with an option of this crate, the option takes precedence:
+    /// the bits of the style, the default button and the modality are always taken from
+    /// the configuration, and so are the icon bits once an icon is set. Windows does not
+    /// validate the flags, so some combinations produce undefined behavior or a dialog
+    /// that fails to appear. Prefer the dedicated methods where they exist.
+    ///
+    /// ```rust
+    /// use win_dialog::WinDialog;
+    /// use windows::Win32::UI::WindowsAndMessaging::{MB_ICONERROR, MB_NOFOCUS, MB_OK};
+    ///
+    /// let dialog = WinDialog::new("Printer is out of paper.").with_raw_flags(MB_NOFOCUS);
+    /// assert_eq!(dialog.style_flags(), MB_NOFOCUS | MB_OK);
+    ///
+    /// let dialog = dialog.with_raw_flags(MB_ICONERROR);
+    /// assert_eq!(dialog.style_flags(), MB_NOFOCUS | MB_ICONERROR | MB_OK);
+    /// ```
+    pub fn with_raw_flags(mut self, flags: MESSAGEBOX_STYLE) -> Self {
+        self.raw_flags |= flags;
+        self
+    }
+
+    /// Same as [WinDialog::set_rtl_locale], but only if the user interface language of
+    /// the current user is Hebrew or Arabic. Otherwise the dialog is left unchanged.
+    pub fn set_rtl_auto(self) -> Self {
+        match ui_language_is_rtl() {
+            true => self.set_rtl_locale(),
+            false => self,
+        }
+    }
+
+    /// The message box becomes the foreground window. Internally, the system calls the
+    /// [SetForegroundWindow](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setforegroundwindow) function for the message box.
+    pub fn set_foreground(mut self) -> Self {
+        self.foreground = true;
+        self
+    }
+
+    /// Undo [WinDialog::set_foreground].
+    pub fn unset_foreground(mut self) -> Self {
+        self.foreground = false;
+        self
+    }
+
+    /// The message box is created with the WS_EX_TOPMOST window style.
+    pub fn set_topmost(mut self) -> Self {
+        self.topmost = true;
+        self
+    }
+
+    /// Undo [WinDialog::set_topmost].
+    pub fn unset_topmost(mut self) -> Self {
+        self.topmost = false;
+        self
+    }
+
+    /// The caller is a service notifying the user of an event. The function displays a message
+    /// box on the current active desktop, even if there is no user logged on to the computer.
+    ///
+    /// Terminal Services: If the calling thread has an impersonation token, the function directs
+    /// the message box to the session specified in the impersonation token.
+    ///
+    /// If this function is set, the window must not have a parent. This is so that the message box
+    /// can appear on a desktop other than the desktop corresponding to the parent window.
+    /// The option is kept when [WinDialog::set_parent_window] is called, but showing the
+    /// message box, and [WinDialogWithParent::validate], then fail with
+    /// [crate::Error::InvalidConfiguration]. It applies again once the parent is removed
+    /// with [WinDialogWithParent::remove_parent].
+    ///
+    /// For information on security considerations in regard to using this flag, see
+    /// [Interactive Services](https://learn.microsoft.com/en-us/windows/win32/services/interactive-services).
+    /// In particular, be aware that this flag can produce interactive content on a locked desktop and
+    /// should therefore be used for only a very limited set of scenarios, such as resource exhaustion.
+    pub fn make_service_notification(mut self) -> Self {
+        self.is_service_notification = true;
+        self
+    }
+
+    /// Undo [WinDialog::make_service_notification].
+    pub fn unset_service_notification(mut self) -> Self {
+        self.is_service_notification = false;
+        self
+    }
+
+    /// Turn off all of the options that are switched on with the `set_` methods, such as
+    /// [WinDialog::set_topmost] and [WinDialog::set_right_justify], as well as
+    /// [WinDialog::make_service_notification]. The content, icon, buttons and other
+    /// settings are kept. Useful when deriving dialogs from a cloned template.
+    pub fn reset_flags(self) -> Self {
+        self.unset_default_desktop_only()
+            .unset_right_justify()
+            .unset_right_to_left_reading()
+            .unset_foreground()
+            .unset_topmost()
+            .unset_service_notification()
+    }
+
+    /// Close the message box automatically if the user has not responded within the given
+    /// duration. When the timeout elapses, [WinDialog::show] returns [crate::Error::Timeout].
+    /// Dialogs that are displayed as a task dialog, see [WinDialog::with_alert], cannot time
+    /// out and fail with [crate::Error::InvalidConfiguration] instead.
+    ///
+    /// Windows measures the timeout in milliseconds using a 32-bit value, so durations
+    /// longer than [u32::MAX] milliseconds (roughly 49.7 days) are clamped to that value.
+    ///
+    /// This relies on the undocumented, but long stable, `MessageBoxTimeoutW` function
+    /// exported by user32.dll.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Play a `.wav` file when the dialog appears, for example for an alarm. If
+    /// `loop_until_closed` is set, the sound repeats until the user responds, otherwise it
+    /// plays once, and it is cut off if the user responds before it ends. The sound is
+    /// played in addition to the sound of the icon, if any.
+    ///
+    /// Only one sound can play at a time in a process, so this stops other sounds started
+    /// with the PlaySound api. If the file does not exist or cannot be played, showing the
+    /// dialog fails with [crate::Error::InvalidSound].
+    pub fn with_sound(mut self, path: impl Into<PathBuf>, loop_until_closed: bool) -> Self {
+        self.sound = Some(Sound::new(path.into(), loop_until_closed));
+        self
+    }
+
+    /// Remove any nul characters from the content and header before displaying them,
+    /// instead of failing with [crate::Error::InvalidString]. For example, the content
+    /// `"a\0b"` is displayed as `"ab"`. Useful when the text comes from a source that
+    /// cannot be trusted to be free of nul characters.
+    pub fn with_lossy_content(mut self) -> Self {
+        self.lossy_content = true;
+        self
+    }
+
+    /// Break the lines of the content so that none of them is longer than the given number
+    /// of characters, for predictable layout of log-like content. Lines are broken at the
+    /// spaces between words, and words longer than a line are broken wherever the line is
+    /// full. Existing line breaks are kept. Zero columns disables wrapping.
+    ///
+    /// Windows breaks lines at its own width as well, so the columns should leave room for
+    /// it. The content is wrapped when the message box is shown; task dialogs, see
+    /// [WinDialog::allow_long_content], lay out their own text and are not affected.
+    ///
+    /// Independently of wrapping, `\r\n` and lone `\r` line endings in the content of
+    /// a message box are always converted to `\n`, which Windows displays consistently.
+    pub fn wrap_at(mut self, columns: usize) -> Self {
+        self.wrap_columns = Some(columns);
+        self
+    }
+
+    /// Set the length of content, in UTF-16 code units, above which the dialog is not
+    /// displayed as a message box. Message boxes do not scroll, so Windows cuts very long
+    /// content off. Showing a dialog with longer content fails with
+    /// [crate::Error::ContentTooLong], unless [WinDialog::allow_long_content] was called.
+    /// The limit defaults to 2000.
+    ///
+    /// The length is that of the text as displayed: the instruction, if any, is counted
+    /// along with the content, after the content is wrapped with [WinDialog::wrap_at].
+    ///
+    /// ```rust
+    /// use win_dialog::{Error, WinDialog};
+    ///
+    /// let dialog = WinDialog::new("Retry?")
+    ///     .with_instruction("The update could not be installed")
+    ///     .with_content_limit(10);
+    /// assert!(matches!(dialog.show(), Err(Error::ContentTooLong(_))));
+    /// ```
+    pub fn with_content_limit(mut self, limit: usize) -> Self {
+        self.content_limit = Some(limit);
+        self
+    }
+
+    /// Display content that is longer than the limit set with [WinDialog::with_content_limit]
+    /// in a task dialog, which can grow to fit it, instead of failing with
+    /// [crate::Error::ContentTooLong]. The task dialog displays the title, instruction,
+    /// content, icon and buttons of the style, is owned by the owner window, if any, and
+    /// returns the same responses. Other options, such as the help button and the
+    /// modality, only apply to message boxes. A task dialog cannot time out, so showing it
+    /// fails with [crate::Error::InvalidConfiguration] if [WinDialog::with_duration] was
+    /// called. The task dialog requires version 6 of the Common Controls library, see
+    /// [WinTaskDialog].
+    pub fn allow_long_content(mut self) -> Self {
+        self.long_content = true;
+        self
+    }
+
+    /// Indicate which set of actions that you want the user to have. Check the available
+    /// options in [crate::style].
+    pub fn with_style<N>(self, style: N) -> WinDialog<N>
+    where
+        N: DialogStyle,
+    {
+        // Destructuring without `..` makes adding a field a compile error here, so that
+        // no option can be forgotten when the style changes.
+        let WinDialog {
+            header,
+            instruction,
+            content,
+            icon,
+            style: _,
+            default_button,
+            modality,
+            default_desktop_only,
+            right_justify_text,
+            right_to_left_reading,
+            foreground,
+            topmost,
+            is_service_notification,
+            duration,
+            lossy_content,
+            show_help_button,
+            content_limit,
+            long_content,
+            sound,
+            silent,
+            language,
+            raw_flags,
+            wrap_columns,
+        } = self;
+
+        WinDialog::<N> {
+            header,
+            instruction,
+            content,
+            icon,
+            style,
+            default_button,
+            modality,
+            default_desktop_only,
+            right_justify_text,
+            right_to_left_reading,
+            foreground,
+            topmost,
+            is_service_notification,
+            duration,
+            lossy_content,
+            show_help_button,
+            content_limit,
+            long_content,
+            sound,
+            silent,
+            language,
+            raw_flags,
+            wrap_columns,
+        }
+    }
+
+    /// Give the dialog a style that is only known at runtime. The style is erased, so the
+    /// dialog is returned as a [DynDialog], which reports an [AnyResponse]. See
+    /// [DynDialog] for an example.
+    pub fn with_dyn_style(self, style: &dyn DynStyle) -> DynDialog {
+        style.erase(self.with_style(Ok_))
+    }
+
+    /// Display the dialog and convert results into proper [Result] type.
+    /// This is a synchronous action.
+    pub fn show(self) -> ShowReturn<T> {
+        self.show_inner(HWND::default())
+    }
+
+    /// Display the dialog and convert the response with the closure, which saves a match
+    /// when only part of the response matters. This is a synchronous action.
+    ///
+    /// ```no_run
+    /// use win_dialog::{style::YesNoResponse, WinDialog, YesNo};
+    ///
+    /// let overwrite = WinDialog::new("Overwrite the existing file?")
+    ///     .with_style(YesNo)
+    ///     .show_map(|response| response == YesNoResponse::Yes)
+    ///     .unwrap();
+    /// ```
+    pub fn show_map<R>(self, f: impl FnOnce(T::Return) -> R) -> crate::Result<R> {
+        self.show().map(f)
+    }
+
+    /// Display the dialog, closing it automatically if the user has not responded within
+    /// the given duration. Unlike [WinDialog::with_duration], the timeout is reported as
+    /// [Timed::TimedOut] rather than as an error.
+    ///
+    /// With the `mock` feature enabled, a timeout can be simulated by injecting
+    /// [IDTIMEOUT](windows::Win32::UI::WindowsAndMessaging::IDTIMEOUT):
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mock")]
+    /// # {
+    /// use std::time::Duration;
+    /// use win_dialog::style::YesNoResponse;
+    /// use win_dialog::{set_mock_response, Timed, WinDialog, YesNo};
+    /// use windows::Win32::UI::WindowsAndMessaging::{IDNO, IDTIMEOUT};
+    ///
+    /// let dialog = || WinDialog::new("Restart now?").with_style(YesNo);
+    ///
+    /// set_mock_response(IDTIMEOUT.0);
+    /// let timed = dialog().show_with_timeout(Duration::from_secs(30)).unwrap();
+    /// assert_eq!(timed, Timed::TimedOut);
+    ///
+    /// set_mock_response(IDNO.0);
+    /// let timed = dialog().show_with_timeout(Duration::from_secs(30)).unwrap();
+    /// assert_eq!(timed, Timed::Responded(YesNoResponse::No));
+    /// # }
+    /// ```
+    pub fn show_with_timeout(self, duration: Duration) -> crate::Result<Timed<T::Return>> {
+        Timed::from_result(self.with_duration(duration).show())
+    }
+
+    /// Display the dialog and return the response along with how long the dialog was
+    /// open before the user responded. This is a synchronous action.
+    pub fn show_timed(self) -> crate::Result<(T::Return, Duration)> {
+        let start = Instant::now();
+        let response = self.show()?;
+        Ok((response, start.elapsed()))
+    }
+
+    /// Display the dialog without blocking the async task that awaits the response. The
+    /// message box is shown on a thread of tokio's blocking pool, with
+    /// [spawn_blocking](tokio::task::spawn_blocking), so this must be called from within a
+    /// tokio runtime. If showing the dialog panics, the panic is propagated to the caller.
+    ///
+    /// Since the dialog runs on a pool thread, it is not modal to any window of the thread
+    /// running the runtime, and [WinDialogWithParent] offers no async counterpart. Only
+    /// available with the `async-tokio` feature enabled.
+    ///
+    /// ```no_run
+    /// use win_dialog::{style::YesNoResponse, WinDialog, YesNo};
+    ///
+    /// async fn confirm_upload() -> win_dialog::Result<bool> {
+    ///     let response = WinDialog::new("Upload the report now?")
+    ///         .with_style(YesNo)
+    ///         .show_async()
+    ///         .await?;
+    ///     Ok(response == YesNoResponse::Yes)
+    /// }
+    /// ```
+    #[cfg(feature = "async-tokio")]
+    pub async fn show_async(self) -> ShowReturn<T>
+    where
+        T: Send + 'static,
+        T::Return: Send + 'static,
+    {
+        #[cfg(feature = "mock")]
+        let mock = crate::mock::current_mock_response();
+        let show = move || {
+            #[cfg(feature = "mock")]
+            crate::mock::inherit_mock_response(mock);
+            self.show()
+        };
+
+        match tokio::task::spawn_blocking(show).await {
+            Ok(result) => result,
+            Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+            Err(_) => Err(crate::Error::RuntimeShutdown),
+        }
+    }
+
+    /// Display the dialog on a dedicated thread and return immediately. The returned
+    /// [DialogHandle] can be used to wait for the user's response, which is useful in
+    /// applications that must keep their own event loop running.
+    ///
+    /// The handle also exposes the window of the dialog once it appears, see
+    /// [DialogHandle::window].
+    pub fn show_detached(self) -> DialogHandle<T>
+    where
+        T: Send + 'static,
+        T::Return: Send + 'static,
+    {
+        self.detach(HWND::default())
+    }
+
+    /// Display the dialog and convert the result into an [AnyResponse] rather than the
+    /// response type of the style. Useful when handling dialogs of different styles
+    /// generically. This is a synchronous action.
+    pub fn show_any(self) -> crate::Result<AnyResponse> {
+        AnyResponse::try_from(self.show_raw(HWND::default())?)
+    }
+
+    /// Runs the operation, and shows the dialog with the error each time it fails, until it
+    /// succeeds, the user gives up or the user chooses to move on. The error is appended
+    /// to the content of the dialog.
+    fn retrying<R, E>(
+        self,
+        owner: HWND,
+        mut operation: impl FnMut() -> Result<R, E>,
+    ) -> Result<Option<R>, RetryError<E>>
+    where
+        E: Display,
+    {
+        loop {
+            let error = match operation() {
+                Ok(value) => return Ok(Some(value)),
+                Err(error) => error,
+            };
+
+            let content = match self.content.is_empty() {
+                true => error.to_string(),
+                false => format!("{}\n\n{error}", self.content),
+            };
+            let raw = self.clone().with_content(content).show_raw(owner)?;
+
+            match AnyResponse::try_from(raw)? {
+                AnyResponse::Retry => continue,
+                AnyResponse::Ignore | AnyResponse::Continue => return Ok(None),
+                _ => return Err(RetryError::Aborted(error)),
+            }
+        }
+    }
+
+    /// Checks the configuration for combinations of options that conflict with each other,
+    /// which Windows either rejects or silently ignores. The dialog is not shown. This is a
+    /// development aid: [WinDialog::show] does not call it. The following are reported as
+    /// [crate::Error::InvalidConfiguration]:
+    ///
+    /// - A default button, set with [WinDialog::set_default_button], that is not displayed
+    ///   by the style, taking the help button into account. Windows then makes the first
+    ///   button the default.
+    /// - [WinDialog::make_service_notification] together with
+    ///   [WinDialog::set_default_desktop_only]. A service notification appears on the
+    ///   active desktop, while the other option waits for the default desktop.
+    /// - [WinDialog::make_service_notification] together with [WinDialog::with_help_button].
+    ///   A service notification has no owner window that could handle the help request.
+    /// - A silent icon, set with [WinDialog::with_alert], together with
+    ///   [WinDialog::with_duration]. The dialog is then displayed as a task dialog, which
+    ///   cannot time out.
+    ///
+    /// Text containing nul characters is not checked, since it is already reported with
+    /// [crate::Error::InvalidString] when the dialog is shown.
+    pub fn validate(&self) -> crate::Result<()> {
+        let button_count = T::BUTTON_COUNT + usize::from(self.show_help_button);
+        if self.default_button.position() > button_count {
+            Err(crate::Error::InvalidConfiguration(format!(
+                "default button {} is not displayed by a {} dialog with {} buttons",
+                self.default_button.position(),
+                T::NAME,
+                button_count
+            )))?;
+        }
+
+        if self.is_service_notification && self.default_desktop_only {
+            Err(crate::Error::InvalidConfiguration(
+                "a service notification cannot be limited to the default desktop".to_string(),
+            ))?;
+        }
+
+        if self.is_service_notification && self.show_help_button {
+            Err(crate::Error::InvalidConfiguration(
+                "a service notification cannot display a help button".to_string(),
+            ))?;
+        }
+
+        if self.silent && self.icon.is_some() && self.duration.is_some() {
+            Err(crate::Error::InvalidConfiguration(
+                "a dialog with a silent icon cannot time out".to_string(),
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the fully resolved parameters of the dialog, as they would be passed to the
+    /// MessageBox api, without showing it. See [DialogDescriptor] for more information.
+    pub fn describe(&self) -> DialogDescriptor {
+        self.descriptor(false)
+    }
+
+    /// Builds the descriptor of the dialog, noting whether it has a parent window.
+    pub(crate) fn descriptor(&self, has_parent: bool) -> DialogDescriptor {
+        let mut buttons = T::BUTTON_LABELS.to_vec();
+        if self.show_help_button {
+            buttons.push("Help");
+        }
+        let default_button = buttons
+            .get(self.default_button.position() - 1)
+            .copied()
+            .unwrap_or(buttons[0]);
+
+        DialogDescriptor {
+            title: self.header.clone(),
+            instruction: self.instruction.clone(),
+            content: self.content.clone(),
+            style: T::NAME,
+            icon: self.icon,
+            modality: self.modality,
+            default_button,
+            buttons,
+            style_flags: self.style_flags().0,
+            duration: self.duration,
+            has_parent,
+        }
+    }
+
+    /// Returns the combination of flags that will be passed to the MessageBox api when the
+    /// dialog is shown, without showing it. Useful for debugging, and for testing the
+    /// configuration of a dialog without displaying a window.
+    ///
+    /// ```rust
+    /// use win_dialog::{Icon, OkCancel, WinDialog};
+    /// use windows::Win32::UI::WindowsAndMessaging::{MB_ICONWARNING, MB_OKCANCEL, MB_TOPMOST};
+    ///
+    /// let dialog = WinDialog::new("Disk space is running low.")
+    ///     .with_style(OkCancel)
+    ///     .with_icon(Icon::Warning)
+    ///     .set_topmost();
+    /// assert_eq!(dialog.style_flags(), MB_TOPMOST | MB_ICONWARNING | MB_OKCANCEL);
+    /// ```
+    pub fn style_flags(&self) -> MESSAGEBOX_STYLE {
+        let icon = self.icon.map(MESSAGEBOX_STYLE::from).unwrap_or_default();
+        let modality = MESSAGEBOX_STYLE::from(self.modality);
+        let default_button = default_button_index(
+            T::BUTTON_COUNT,
+            self.show_help_button,
+            self.default_button.position(),
+        );
+        let default_deskop_only = match self.default_desktop_only {
+            true => MB_DEFAULT_DESKTOP_ONLY,
+            false => MESSAGEBOX_STYLE::default(),
+        };
+        let right_justify = match self.right_justify_text {
+            true => MB_RIGHT,
+            false => MESSAGEBOX_STYLE::default(),
+        };
+        let right_to_left_reading = match self.right_to_left_reading {
+            true => MB_RTLREADING,
+            false => MESSAGEBOX_STYLE::default(),
+        };
+
+        let foreground = match self.foreground {
+            true => MB_SETFOREGROUND,
+            false => MESSAGEBOX_STYLE::default(),
+        };
+
+        let topmost = match self.topmost {
+            true => MB_TOPMOST,
+            false => MESSAGEBOX_STYLE::default(),
+        };
+
+        let is_service_notif = match self.is_service_notification {
+            true => MB_SERVICE_NOTIFICATION,
+            false => MESSAGEBOX_STYLE::default(),
+        };
+
+        let help_button = match self.show_help_button {
+            true => MB_HELP,
+            false => MESSAGEBOX_STYLE::default(),
+        };
+
+        // The options of this crate take precedence over raw flags in the same field.
+        let modeled = match self.icon {
+            Some(_) => MB_TYPEMASK | MB_DEFMASK | MB_MODEMASK | MB_ICONMASK,
+            None => MB_TYPEMASK | MB_DEFMASK | MB_MODEMASK,
+        };
+        let raw_flags = self.raw_flags & !modeled;
+
+        self.style.into()
+            | icon
+            | help_button
+            | modality
+            | default_button
+            | default_deskop_only
+            | right_justify
+            | right_to_left_reading
+            | foreground
+            | topmost
+            | is_service_notif
+            | raw_flags
+    }
+
+    /// Displays the dialog on a new thread, sharing its window with the returned handle.
+    fn detach(self, owner: HWND) -> DialogHandle<T>
+    where
+        T: Send + 'static,
+        T::Return: Send + 'static,
+    {
+        let window = Arc::new(DialogWindow::default());
+        let shared = Arc::clone(&window);
+        #[cfg(feature = "mock")]
+        let mock = crate::mock::current_mock_response();
+        let thread = std::thread::spawn(move || {
+            #[cfg(feature = "mock")]
+            crate::mock::inherit_mock_response(mock);
+            let closed = CloseOnDrop(shared);
+            let created = Arc::clone(&closed.0);
+            on_dialog_created(
+                move |window| created.open(window),
+                || self.show_inner(owner),
+            )
+        });
+
+        DialogHandle::new(thread, window)
+    }
+
+    /// Invokes the MessageBox api and converts the raw result into the response type of
+    /// the style.
+    fn show_inner(self, owner: HWND) -> crate::Result<T::Return> {
+        T::Return::try_from(self.show_raw(owner)?).map_err(|error| match error {
+            crate::Error::UnknownResponseCode(code) => crate::Error::UnknownResponseCodeForStyle {
+                code,
+                style: T::NAME,
+            },
+            error => error,
+        })
+    }
+
+    /// Displays the message box and returns the raw result. Every way of showing a
+    /// [WinDialog] goes through here, so the result is passed to the observer registered
+    /// with [crate::set_result_observer], if any, whichever way the dialog was shown. With
+    /// the `tracing` feature enabled, the dialog is shown inside a span describing it, and
+    /// the outcome is recorded as an event.
+    pub(crate) fn show_raw(self, owner: HWND) -> crate::Result<MESSAGEBOX_RESULT> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "win_dialog::show",
+            style = T::NAME,
+            icon = ?self.icon,
+            modality = ?self.modality,
+            header = self.header.as_deref(),
+        )
+        .entered();
+
+        let observed = crate::observer::result_observer()
+            .map(|observer| (observer, self.descriptor(owner != HWND::default())));
+
+        let result = self.display(owner);
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(response) => tracing::info!(response = response.0, "dialog closed"),
+            Err(error) => tracing::warn!(%error, "dialog failed"),
+        }
+
+        if let (Some((observer, descriptor)), Ok(response)) = (observed, &result) {
+            observer(&descriptor, response.0);
+        }
+
+        result
+    }
+
+    /// Converts the Rust types to their C counterparts and invokes the MessageBox
+    /// api. Content longer than the limit is displayed in a task dialog or rejected, see
+    /// [WinDialog::allow_long_content], and silent icons are displayed in a task dialog,
+    /// see [WinDialog::with_alert].
+    ///
+    /// The stored strings are borrowed and encoded straight into the UTF-16 buffers that
+    /// Windows reads, so showing allocates a single buffer per text, even when the
+    /// instruction is prepended to the content.
+    fn display(self, owner: HWND) -> crate::Result<MESSAGEBOX_RESULT> {
+        if owner != HWND::default() {
+            check_no_service_notification(self.is_service_notification)?;
+        }
+
+        #[cfg(feature = "record")]
+        crate::record::record(self.descriptor(owner != HWND::default()));
+
+        // Stops the sound when the dialog closes, or when showing it fails.
+        let _sound = self.sound.as_ref().map(Sound::play).transpose()?;
+
+        let text = layout_content(&self.content, self.wrap_columns);
+        let encode = |parts: &[&str]| to_wide_joined(parts, self.lossy_content);
+        let content = match &self.instruction {
+            Some(instruction) => encode(&[instruction, "\n\n", &text])?,
+            None => encode(&[&text])?,
+        };
+
+        // The limit applies to the text as displayed, without the nul terminator.
+        let length = content.len() - 1;
+        if length > self.content_limit.unwrap_or(DEFAULT_CONTENT_LIMIT) {
+            return match self.long_content {
+                true => self.show_as_task_dialog(owner),
+                false => Err(crate::Error::ContentTooLong(length)),
+            };
+        }
+        if self.silent && self.icon.is_some() {
+            return self.show_as_task_dialog(owner);
+        }
+
+        let header = self
+            .header
+            .as_deref()
+            .map(|header| encode(&[header]))
+            .transpose()?;
+
+        let style = self.style_flags();
+
+        let _language = self.language.map(ThreadLanguage::set).transpose()?;
+        message_box(owner, &content, header.as_deref(), style, self.duration)
+    }
+
+    /// Displays the dialog as a task dialog with the buttons of the style, owned by the
+    /// owner window if there is one, and reports the clicked button as the MessageBox api
+    /// would. The icon is silent if requested with [WinDialog::with_alert].
+    fn show_as_task_dialog(self, owner: HWND) -> crate::Result<MESSAGEBOX_RESULT> {
+        if self.duration.is_some() {
+            return Err(crate::Error::InvalidConfiguration(
+                "a dialog displayed as a task dialog cannot time out".to_string(),
+            ));
+        }
+
+        let clean = |value: String| match self.lossy_content {
+            true => value.replace('\0', ""),
+            false => value,
+        };
+        let buttons = T::BUTTON_IDS
+            .iter()
+            .zip(T::BUTTON_LABELS)
+            .map(|(id, label)| CustomButton::new(id.0, *label))
+            .collect();
+
+        let mut dialog = WinTaskDialog::new(clean(self.content))
+            .with_buttons(buttons)
+            .set_parent_window(owner);
+        if let Some(title) = self.header {
+            dialog = dialog.with_title(clean(title));
+        }
+        if let Some(instruction) = self.instruction {
+            dialog = dialog.with_instruction(clean(instruction));
+        }
+        if let Some(icon) = self.icon {
+            dialog = dialog.with_icon(icon);
+        }
+        if self.silent {
+            dialog = dialog.set_silent();
+        }
+        if let Some(language) = self.language {
+            dialog = dialog.with_language(language);
+        }
+        if let Some(id) = T::BUTTON_IDS.get(self.default_button.position() - 1) {
+            dialog = dialog.default_button(id.0);
+        }
+
+        Ok(MESSAGEBOX_RESULT(dialog.show()?.response))
+    }
+}
+
+/// Reports a service notification that was given a parent window, which Windows does not
+/// allow.
+fn check_no_service_notification(is_service_notification: bool) -> crate::Result<()> {
+    match is_service_notification {
+        true => Err(crate::Error::InvalidConfiguration(
+            "a service notification cannot have a parent window".to_string(),
+        )),
+        false => Ok(()),
+    }
+}
+
+impl From<&str> for WinDialog<Ok_> {
+    /// Creates a dialog with the given content. See [WinDialog::new].
+    ///
+    /// ```rust
+    /// use win_dialog::WinDialog;
+    ///
+    /// let dialog: WinDialog = "Are you sure?".into();
+    /// assert_eq!(dialog, WinDialog::new("Are you sure?"));
+    /// ```
+    fn from(content: &str) -> Self {
+        WinDialog::new(content)
+    }
+}
+
+impl From<String> for WinDialog<Ok_> {
+    /// Creates a dialog with the given content. See [WinDialog::new].
+    fn from(content: String) -> Self {
+        WinDialog::new(content)
+    }
+}
+
+impl From<&std::io::Error> for WinDialog<Ok_> {
+    /// Creates an error dialog displaying the io error. See [WinDialog::from_error].
+    fn from(error: &std::io::Error) -> Self {
+        WinDialog::from_error(error)
+    }
+}
+
+/// The outcome of a dialog that closes itself after a while, returned by
+/// [WinDialog::show_with_timeout]. Keeps the timeout apart from the responses of the
+/// style, which only describe the buttons the user can click.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Timed<R> {
+    /// The user responded before the timeout elapsed.
+    Responded(R),
+    /// The dialog closed itself because the user did not respond in time.
+    TimedOut,
+}
+
+impl<R> Timed<R> {
+    /// Returns the response, or [None] if the dialog timed out.
+    pub fn responded(self) -> Option<R> {
+        match self {
+            Timed::Responded(response) => Some(response),
+            Timed::TimedOut => None,
+        }
+    }
+
+    /// Returns true if the dialog closed itself because the user did not respond in time.
+    pub fn is_timed_out(&self) -> bool {
+        matches!(self, Timed::TimedOut)
+    }
+
+    /// Turns [crate::Error::Timeout] into [Timed::TimedOut], keeping other errors.
+    fn from_result(result: crate::Result<R>) -> crate::Result<Self> {
+        match result {
+            Ok(response) => Ok(Timed::Responded(response)),
+            Err(crate::Error::Timeout) => Ok(Timed::TimedOut),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// A response that covers every button a message box can display, regardless of its
+/// style. Returned by [WinDialog::show_any] for callers who want to handle dialogs of
+/// different styles in the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnyResponse {
+    /// The user clicked 'ok'.
+    Ok,
+    /// The user clicked 'cancel' or closed the message box.
+    Cancel,
+    /// The user clicked 'yes'.
+    Yes,
+    /// The user clicked 'no'.
+    No,
+    /// The user clicked 'abort'.
+    Abort,
+    /// The user clicked 'retry' or 'try again'.
+    Retry,
+    /// The user clicked 'ignore'.
+    Ignore,
+    /// The user clicked 'continue'.
+    Continue,
+}
+
+impl AnyResponse {
+    /// Returns true if the response is [AnyResponse::Ok].
+    pub fn is_ok(&self) -> bool {
+        matches!(self, AnyResponse::Ok)
+    }
+
+    /// Returns true if the response is [AnyResponse::Cancel].
+    pub fn is_cancel(&self) -> bool {
+        matches!(self, AnyResponse::Cancel)
+    }
+
+    /// Returns true if the response is [AnyResponse::Yes].
+    pub fn is_yes(&self) -> bool {
+        matches!(self, AnyResponse::Yes)
+    }
+
+    /// Returns true if the response is [AnyResponse::No].
+    pub fn is_no(&self) -> bool {
+        matches!(self, AnyResponse::No)
+    }
+
+    /// Returns true if the response is [AnyResponse::Abort].
+    pub fn is_abort(&self) -> bool {
+        matches!(self, AnyResponse::Abort)
+    }
+
+    /// Returns true if the response is [AnyResponse::Retry].
+    pub fn is_retry(&self) -> bool {
+        matches!(self, AnyResponse::Retry)
+    }
+
+    /// Returns true if the response is [AnyResponse::Ignore].
+    pub fn is_ignore(&self) -> bool {
+        matches!(self, AnyResponse::Ignore)
+    }
+
+    /// Returns true if the response is [AnyResponse::Continue].
+    pub fn is_continue(&self) -> bool {
+        matches!(self, AnyResponse::Continue)
+    }
+
+    /// Returns true if the user agreed to carry on, i.e. clicked 'ok', 'yes', 'retry',
+    /// 'ignore' or 'continue'. This matches [crate::style::AbortRetryIgnoreResponse::is_affirmative]
+    /// and the other style specific definitions.
+    pub fn is_affirmative(&self) -> bool {
+        matches!(
+            self,
+            AnyResponse::Ok
+                | AnyResponse::Yes
+                | AnyResponse::Retry
+                | AnyResponse::Ignore
+                | AnyResponse::Continue
+        )
+    }
+
+    /// The label of the button that produces this response, in lowercase, such as `"ok"`.
+    /// The English label is returned regardless of the language of the system. Used by
+    /// the [Display](std::fmt::Display) implementation and by comparisons with strings.
+    /// [AnyResponse::Retry] is `"retry"` for the 'try again' button as well, like
+    /// [CancelRetryContinueResponse::label].
+    pub fn label(&self) -> &'static str {
+        match self {
+            AnyResponse::Ok => "ok",
+            AnyResponse::Cancel => "cancel",
+            AnyResponse::Yes => "yes",
+            AnyResponse::No => "no",
+            AnyResponse::Abort => "abort",
+            AnyResponse::Retry => "retry",
+            AnyResponse::Ignore => "ignore",
+            AnyResponse::Continue => "continue",
+        }
+    }
+
+    /// The exit code of a command line tool that reports this response, so that scripts can
+    /// tell the user's choice without parsing any output. The mapping is stable, and the
+    /// `exit_code` methods of the style specific responses follow it as well:
+    ///
+    /// | Response               | Exit code |
+    /// |------------------------|-----------|
+    /// | Ok, Yes, Continue      | 0         |
+    /// | Cancel                 | 1         |
+    /// | Abort                  | 2         |
+    /// | Retry                  | 3         |
+    /// | Ignore                 | 4         |
+    /// | No                     | 5         |
+    ///
+    /// ```no_run
+    /// use win_dialog::WinDialog;
+    ///
+    /// let response = WinDialog::confirm("Install the update now?").show().unwrap();
+    /// std::process::exit(response.exit_code());
+    /// ```
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AnyResponse::Ok | AnyResponse::Yes | AnyResponse::Continue => 0,
+            AnyResponse::Cancel => 1,
+            AnyResponse::Abort => 2,
+            AnyResponse::Retry => 3,
+            AnyResponse::Ignore => 4,
+            AnyResponse::No => 5,
+        }
+    }
+
+    /// The response code that Windows reports for this response, such as `IDOK`. The
+    /// inverse of the [TryFrom] conversion from [MESSAGEBOX_RESULT]. Since the 'retry' and
+    /// 'try again' buttons both map to [AnyResponse::Retry], it is reported as `IDRETRY`.
+    pub fn as_code(&self) -> i32 {
+        match self {
+            AnyResponse::Ok => IDOK.0,
+            AnyResponse::Cancel => IDCANCEL.0,
+            AnyResponse::Yes => IDYES.0,
+            AnyResponse::No => IDNO.0,
+            AnyResponse::Abort => IDABORT.0,
+            AnyResponse::Retry => IDRETRY.0,
+            AnyResponse::Ignore => IDIGNORE.0,
+            AnyResponse::Continue => IDCONTINUE.0,
+        }
+    }
+}
+
+impl TryFrom<MESSAGEBOX_RESULT> for AnyResponse {
+    type Error = crate::Error;
+
+    fn try_from(value: MESSAGEBOX_RESULT) -> Result<Self, Self::Error> {
+        let converted = if value == IDOK {
+            AnyResponse::Ok
+        } else if value == IDCANCEL {
+            AnyResponse::Cancel
+        } else if value == IDYES {
+            AnyResponse::Yes
+        } else if value == IDNO {
+            AnyResponse::No
+        } else if value == IDABORT {
+            AnyResponse::Abort
+        } else if value == IDRETRY || value == IDTRYAGAIN {
+            AnyResponse::Retry
+        } else if value == IDIGNORE {
+            AnyResponse::Ignore
+        } else if value == IDCONTINUE {
+            AnyResponse::Continue
+        } else {
+            Err(unrecognized(value))?
+        };
+
+        Ok(converted)
+    }
+}
+
+/// Converts the response back to the code that Windows reports for it, see
+/// [AnyResponse::as_code].
+impl From<AnyResponse> for MESSAGEBOX_RESULT {
+    fn from(value: AnyResponse) -> Self {
+        MESSAGEBOX_RESULT(value.as_code())
+    }
+}
+
+crate::style::label_impls!(AnyResponse);
+
+/// Invokes the MessageBox api with the owner window and the encoded content and header. Uses MessageBoxTimeoutW
+/// when a duration was set, and MessageBoxW otherwise.
+#[cfg(all(windows, not(feature = "mock")))]
+fn message_box(
+    owner: HWND,
+    content: &[u16],
+    header: Option<&[u16]>,
+    style: MESSAGEBOX_STYLE,
+    duration: Option<Duration>,
+) -> crate::Result<MESSAGEBOX_RESULT> {
+    // The pointers borrow the encoded buffers, which outlive the call below.
+    let content_ptr = PCWSTR::from_raw(content.as_ptr());
+    let header_ptr = header
+        .map(|header| PCWSTR::from_raw(header.as_ptr()))
+        .unwrap_or(PCWSTR::null());
+
+    let result = match duration {
+        Some(duration) => {
+            let milliseconds = u32::try_from(duration.as_millis()).unwrap_or(u32::MAX);
+            unsafe { MessageBoxTimeoutW(owner, content_ptr, header_ptr, style, 0, milliseconds) }
+        }
+        None => unsafe { MessageBoxW(owner, content_ptr, header_ptr, style) },
+    };
+
+    // MessageBox only returns zero when it failed to create the message box.
+    if result.0 == 0 {
+        return Err(crate::Error::SystemError(unsafe { GetLastError() }));
+    }
+
+    Ok(result)
+}
+
+/// Message boxes are only available on Windows. On other platforms the dialog is
+/// fully configurable, but showing it always fails with [crate::Error::Unsupported].
+#[cfg(all(not(windows), not(feature = "mock")))]
+fn message_box(
+    _owner: HWND,
+    _content: &[u16],
+    _header: Option<&[u16]>,
+    _style: MESSAGEBOX_STYLE,
+    _duration: Option<Duration>,
+) -> crate::Result<MESSAGEBOX_RESULT> {
+    Err(crate::Error::Unsupported)
+}
+
+/// With the `mock` feature enabled, no message box is displayed. Instead, the response
+/// injected with [crate::set_mock_response] is returned.
+#[cfg(feature = "mock")]
+fn message_box(
+    _owner: HWND,
+    _content: &[u16],
+    _header: Option<&[u16]>,
+    _style: MESSAGEBOX_STYLE,
+    _duration: Option<Duration>,
+) -> crate::Result<MESSAGEBOX_RESULT> {
+    crate::mock::mock_response()
+}
+
+/// Wrapper around the undocumented `MessageBoxTimeoutW` export of user32.dll. Behaves like
+/// MessageBoxW, but closes the message box and returns [IDTIMEOUT](windows::Win32::UI::WindowsAndMessaging::IDTIMEOUT)
+/// if the user does not respond within the given number of milliseconds.
+#[cfg(all(windows, not(feature = "mock")))]
+#[allow(non_snake_case)]
+unsafe fn MessageBoxTimeoutW(
+    hwnd: HWND,
+    text: PCWSTR,
+    caption: PCWSTR,
+    style: MESSAGEBOX_STYLE,
+    language_id: u16,
+    milliseconds: u32,
+) -> MESSAGEBOX_RESULT {
+    windows_targets::link!("user32.dll" "system" fn MessageBoxTimeoutW(hwnd: HWND, lptext: PCWSTR, lpcaption: PCWSTR, utype: MESSAGEBOX_STYLE, wlanguageid: u16, dwmilliseconds: u32) -> MESSAGEBOX_RESULT);
+    MessageBoxTimeoutW(hwnd, text, caption, style, language_id, milliseconds)
+}
+
+/// Whether the user interface language of the current user, as reported by
+/// [GetUserDefaultUILanguage](https://learn.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-getuserdefaultuilanguage),
+/// is Hebrew or Arabic.
+#[cfg(windows)]
+fn ui_language_is_rtl() -> bool {
+    windows_targets::link!("kernel32.dll" "system" fn GetUserDefaultUILanguage() -> u16);
 
-    /// The message box is created with the WS_EX_TOPMOST window style.
-    pub fn set_topmost(mut self) -> Self {
-        self.topmost = true;
-        self
+    is_rtl_language(unsafe { GetUserDefaultUILanguage() })
+}
+
+/// There is no Windows user interface language on other platforms.
+#[cfg(not(windows))]
+fn ui_language_is_rtl() -> bool {
+    false
+}
+
+/// Describes a failed process that has no exit code because it was terminated by a signal.
+/// See [WinDialog::from_exit_status].
+#[cfg(unix)]
+fn exit_signal_detail(status: std::process::ExitStatus) -> String {
+    use std::os::unix::process::ExitStatusExt;
+
+    match status.signal() {
+        Some(signal) => format!("The process was terminated by signal {signal}."),
+        None => String::from("The process exited without an exit code."),
     }
+}
 
-    /// The caller is a service notifying the user of an event. The function displays a message
-    /// box on the current active desktop, even if there is no user logged on to the computer.
+/// Processes always have an exit code on Windows, so this is only reached on platforms
+/// that are neither Windows nor Unix.
+#[cfg(not(unix))]
+fn exit_signal_detail(_status: std::process::ExitStatus) -> String {
+    String::from("The process exited without an exit code.")
+}
 
-    /// Terminal Services: If the calling thread has an impersonation token, the function directs
-    /// the message box to the session specified in the impersonation token.
-    ///
-    /// If this function is set, the window must not have a parent. This is so that the message box
-    /// can appear on a desktop other than the desktop corresponding to the parent window.
-    /// For this reason, if you call [WinDialog::set_parent_window], this parameter will be unset.
-    ///
-    /// For information on security considerations in regard to using this flag, see
-    /// [Interactive Services](https://learn.microsoft.com/en-us/windows/win32/services/interactive-services).
-    /// In particular, be aware that this flag can produce interactive content on a locked desktop and
-    /// should therefore be used for only a very limited set of scenarios, such as resource exhaustion.
-    pub fn make_service_notification(mut self) -> Self {
-        self.is_service_notification = true;
-        self
+/// Converts the line endings of the content to `\n`, and breaks its lines at the given
+/// number of columns, if any. See [WinDialog::wrap_at]. Content that needs neither is
+/// borrowed as is.
+fn layout_content(content: &str, columns: Option<usize>) -> Cow<'_, str> {
+    let content = match content.contains('\r') {
+        true => Cow::Owned(content.replace("\r\n", "\n").replace('\r', "\n")),
+        false => Cow::Borrowed(content),
+    };
+
+    match columns {
+        Some(columns) if columns > 0 => Cow::Owned(
+            content
+                .split('\n')
+                .map(|line| wrap_line(line, columns))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        _ => content,
     }
+}
 
-    /// Indicate which set of actions that you want the user to have. Check the available
-    /// options in [crate::style].
-    pub fn with_style<N>(self, style: N) -> WinDialog<N>
-    where
-        N: DialogStyle,
-    {
-        WinDialog::<N> {
-            header: self.header,
-            content: self.content,
-            style,
-            foreground: self.foreground,
-            right_to_left_reading: self.right_to_left_reading,
-            icon: self.icon,
-            default_button: self.default_button,
-            modality: self.modality,
-            topmost: self.topmost,
-            is_service_notification: self.is_service_notification,
-            default_desktop_only: self.default_desktop_only,
-            right_justify_text: self.right_justify_text,
+/// Breaks a single line at the spaces between words so that no line is longer than the
+/// given number of characters. Words that are longer on their own are broken where the
+/// line is full.
+fn wrap_line(line: &str, columns: usize) -> String {
+    let mut wrapped = String::with_capacity(line.len());
+    // The number of characters on the last line of `wrapped`.
+    let mut length = 0;
+
+    for (index, word) in line.split(' ').enumerate() {
+        if index > 0 {
+            match length + 1 + word.chars().count() <= columns {
+                true => {
+                    wrapped.push(' ');
+                    length += 1;
+                }
+                false => {
+                    wrapped.push('\n');
+                    length = 0;
+                }
+            }
         }
-    }
 
-    /// Display the dialog and convert results into proper [Result] type.
-    /// This is a synchronous action.
-    pub fn show(self) -> ShowReturn<T> {
-        self.show_inner(Default::default())
+        for character in word.chars() {
+            if length == columns {
+                wrapped.push('\n');
+                length = 0;
+            }
+            wrapped.push(character);
+            length += 1;
+        }
     }
 
-    /// Converts the Rust types to their C counterparts and invokes the MessageBox
-    /// api.
-    fn show_inner(self, help_button: MESSAGEBOX_STYLE) -> crate::Result<T::Return> {
-        let content = CString::new(self.content.to_string())?;
-        let content_ptr = PCSTR::from_raw(content.as_ptr() as *const u8);
-
-        let header_ptr = if let Some(header) = self.header {
-            let cstr_header = CString::new(header)?;
-            let header_ptr = PCSTR::from_raw(cstr_header.as_ptr() as *const u8);
-            Some(header_ptr)
-        } else {
-            None
-        };
-
-        let icon = self.icon.map(MESSAGEBOX_STYLE::from).unwrap_or_default();
-        let default_button = self.default_button;
-        let default_deskop_only = match self.default_desktop_only {
-            true => MB_DEFAULT_DESKTOP_ONLY,
-            false => MESSAGEBOX_STYLE::default(),
-        };
-        let right_justify = match self.right_justify_text {
-            true => MB_RIGHT,
-            false => MESSAGEBOX_STYLE::default(),
-        };
-        let right_to_left_reading = match self.right_to_left_reading {
-            true => MB_RTLREADING,
-            false => MESSAGEBOX_STYLE::default(),
-        };
+    wrapped
+}
 
-        let foreground = match self.foreground {
-            true => MB_SETFOREGROUND,
-            false => MESSAGEBOX_STYLE::default(),
-        };
+/// Encodes a string as the nul-terminated UTF-16 buffer expected by the wide
+/// variants of the Windows api. Fails if the string contains an interior nul,
+/// since Windows would silently truncate the text at that point.
+pub(crate) fn to_wide(value: &str) -> crate::Result<Vec<u16>> {
+    to_wide_joined(&[value], false)
+}
 
-        let topmost = match self.topmost {
-            true => MB_TOPMOST,
-            false => MESSAGEBOX_STYLE::default(),
-        };
+/// Encodes the concatenation of the parts like [to_wide], straight into a single buffer
+/// that is allocated once. If `lossy` is set, interior nul characters are removed instead
+/// of failing.
+fn to_wide_joined(parts: &[&str], lossy: bool) -> crate::Result<Vec<u16>> {
+    // A string never has more UTF-16 units than UTF-8 bytes, so the buffer never grows.
+    let capacity = parts.iter().map(|part| part.len()).sum::<usize>() + 1;
+    let mut wide = Vec::with_capacity(capacity);
+    for part in parts {
+        wide.extend(part.encode_utf16());
+    }
 
-        let is_service_notif = match self.is_service_notification {
-            true => MB_SERVICE_NOTIFICATION,
-            false => MESSAGEBOX_STYLE::default(),
-        };
+    match wide.iter().position(|unit| *unit == 0) {
+        Some(_) if lossy => wide.retain(|unit| *unit != 0),
+        Some(position) => Err(crate::Error::InvalidString(position))?,
+        None => {}
+    }
 
-        let result = unsafe {
-            MessageBoxA(
-                None,
-                content_ptr,
-                header_ptr.as_ref(),
-                self.style.into()
-                    | icon
-                    | help_button
-                    | default_button
-                    | default_deskop_only
-                    | right_justify
-                    | right_to_left_reading
-                    | foreground
-                    | topmost
-                    | is_service_notif,
-            )
-        };
+    wide.push(0);
+    Ok(wide)
+}
 
-        T::Return::try_from(result)
+impl WinDialog<Ok_> {
+    /// Display the dialog and wait for the user to dismiss it, ignoring the response.
+    /// Intended for "press OK to continue" messages, where the only possible response
+    /// carries no information and handling the result is noise.
+    ///
+    /// Errors, such as the dialog failing to appear, are swallowed as well. With the
+    /// `tracing` feature enabled, they are still logged as warnings. Use [WinDialog::show]
+    /// when the caller must know whether the message was displayed.
+    pub fn notify(self) {
+        let _ = self.show();
     }
 }
 
 impl WinDialog<OkCancel> {
     /// Make [crate::style::OkCancelResponse::Cancel] the default response,
-    pub fn set_default_cancel(mut self) -> Self {
-        self.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_cancel(self) -> Self {
+        self.set_default(OkCancelResponse::Cancel)
     }
 }
 
 impl WinDialog<AbortRetryIgnore> {
+    /// Run a fallible operation, and let the user decide what to do each time it fails.
+    /// The dialog is shown with the error appended to its content. 'Retry' runs the
+    /// operation again, 'abort' returns [RetryError::Aborted] with the error, and 'ignore'
+    /// returns `Ok(None)` to signal that the operation was skipped. If the operation
+    /// succeeds, its result is returned as `Ok(Some(value))`.
+    ///
+    /// ```no_run
+    /// use win_dialog::{style::AbortRetryIgnore, Icon, WinDialog};
+    ///
+    /// let contents = WinDialog::new("The settings could not be loaded.")
+    ///     .with_style(AbortRetryIgnore)
+    ///     .with_icon(Icon::Error)
+    ///     .show_retrying(|| std::fs::read_to_string("settings.toml"));
+    /// ```
+    pub fn show_retrying<R, E>(
+        self,
+        operation: impl FnMut() -> Result<R, E>,
+    ) -> Result<Option<R>, RetryError<E>>
+    where
+        E: Display,
+    {
+        self.retrying(HWND::default(), operation)
+    }
+
     /// Make [crate::style::AbortRetryIgnoreResponse::Retry] the default response,
-    pub fn set_default_retry(mut self) -> Self {
-        self.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_retry(self) -> Self {
+        self.set_default(AbortRetryIgnoreResponse::Retry)
     }
 
     /// Make [crate::style::AbortRetryIgnoreResponse::Ignore] the default response,
-    pub fn set_default_ignore(mut self) -> Self {
-        self.default_button = MB_DEFBUTTON3;
-        self
+    pub fn set_default_ignore(self) -> Self {
+        self.set_default(AbortRetryIgnoreResponse::Ignore)
     }
 }
 
 impl WinDialog<YesNoCancel> {
     /// Make [crate::style::YesNoCancelResponse::No] the default response,
-    pub fn set_default_no(mut self) -> Self {
-        self.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_no(self) -> Self {
+        self.set_default(YesNoCancelResponse::No)
     }
 
     /// Make [crate::style::YesNoCancelResponse::Cancel] the default response.
-    pub fn set_default_cancel(mut self) -> Self {
-        self.default_button = MB_DEFBUTTON3;
-        self
+    pub fn set_default_cancel(self) -> Self {
+        self.set_default(YesNoCancelResponse::Cancel)
     }
 }
 
 impl WinDialog<YesNo> {
     /// Make [crate::style::YesNoResponse::No] the default response.
-    pub fn set_default_no(mut self) -> Self {
-        self.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_no(self) -> Self {
+        self.set_default(YesNoResponse::No)
     }
 }
 
 impl WinDialog<RetryCancel> {
+    /// Run a fallible operation, and show the dialog with the error each time it fails.
+    /// 'Retry' runs the operation again, and 'cancel' returns [RetryError::Aborted] with
+    /// the error. Works like `show_retrying` on `WinDialog<AbortRetryIgnore>`, except that
+    /// the operation cannot be skipped.
+    pub fn show_retrying<R, E>(
+        self,
+        operation: impl FnMut() -> Result<R, E>,
+    ) -> Result<Option<R>, RetryError<E>>
+    where
+        E: Display,
+    {
+        self.retrying(HWND::default(), operation)
+    }
+
     /// Make [crate::style::RetryCancelResponse::Cancel] the default response.
-    pub fn set_default_cancel(mut self) -> Self {
-        self.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_cancel(self) -> Self {
+        self.set_default(RetryCancelResponse::Cancel)
     }
 }
 
 impl WinDialog<CancelRetryContinue> {
+    /// Run a fallible operation, and show the dialog with the error each time it fails.
+    /// 'Try again' runs the operation again, 'cancel' returns [RetryError::Aborted] with
+    /// the error, and 'continue' returns `Ok(None)`. Works like `show_retrying` on
+    /// `WinDialog<AbortRetryIgnore>`.
+    pub fn show_retrying<R, E>(
+        self,
+        operation: impl FnMut() -> Result<R, E>,
+    ) -> Result<Option<R>, RetryError<E>>
+    where
+        E: Display,
+    {
+        self.retrying(HWND::default(), operation)
+    }
+
     /// Make [crate::style::CancelRetryContinueResponse::Retry] the default response.
-    pub fn set_default_retry(mut self) -> Self {
-        self.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_retry(self) -> Self {
+        self.set_default(CancelRetryContinueResponse::Retry)
     }
 
     /// Make [crate::style::CancelRetryContinueResponse::Continue] the default response.
-    pub fn set_default_continue(mut self) -> Self {
-        self.default_button = MB_DEFBUTTON3;
-        self
+    pub fn set_default_continue(self) -> Self {
+        self.set_default(CancelRetryContinueResponse::Continue)
     }
 }
 
 /// A Message Box with an attached parent window.
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct WinDialogWithParent<T>
 where
     T: DialogStyle,
@@ -349,9 +1865,6 @@ where
     /// A pointer to a parent window. Its not expected to be needed for the typical
     /// use cases of this crate, but is included here for completeness.
     window_handle: HWND,
-
-    /// Indicates whether this message box should display a help button.
-    show_help_button: bool,
 }
 
 impl<T> WinDialogWithParent<T>
@@ -362,7 +1875,7 @@ where
     /// or presses F1, the system sends a [WM_HELP](https://learn.microsoft.com/en-us/windows/win32/shell/wm-help)
     /// message to the parent window.
     pub fn with_help_button(mut self) -> Self {
-        self.show_help_button = true;
+        self.inner.show_help_button = true;
         self
     }
 
@@ -373,20 +1886,111 @@ where
         self
     }
 
-    /// Set an [Icon] for the dialog box.
+    /// Sets the text of the title bar. See [WinDialog::with_title] for more information.
+    pub fn with_title(self, title: impl Into<String>) -> Self {
+        self.with_header(title)
+    }
+
+    /// Set a main instruction, placed above the content.
+    /// See [WinDialog::with_instruction] for more information.
+    pub fn with_instruction(mut self, instruction: impl Into<String>) -> Self {
+        self.inner.instruction = Some(instruction.into());
+        self
+    }
+
+    /// Replaces the body text of the message box.
+    /// See [WinDialog::with_content] for more information.
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.inner.content = content.into();
+        self
+    }
+
+    /// Set an [Icon] for the dialog box. See [WinDialog::with_icon] for more information.
     pub fn with_icon(mut self, icon: impl Into<Icon>) -> Self {
         self.inner.icon = Some(icon.into());
         self
     }
 
+    /// Display the dialog without an icon. See [WinDialog::without_icon] for more
+    /// information.
+    pub fn without_icon(mut self) -> Self {
+        self.inner.icon = None;
+        self
+    }
+
+    /// Set an [Icon] for the dialog box, and choose whether its system sound is played.
+    /// See [WinDialog::with_alert] for more information.
+    pub fn with_alert(mut self, icon: impl Into<Icon>, play_sound: bool) -> Self {
+        self.inner.icon = Some(icon.into());
+        self.inner.silent = !play_sound;
+        self
+    }
+
+    /// Checks the configuration for conflicting options without showing the message box.
+    /// See [WinDialog::validate] for the combinations that are checked.
+    ///
+    /// In addition, a dialog that is a service notification is reported, since a service
+    /// notification cannot have a parent window.
+    pub fn validate(&self) -> crate::Result<()> {
+        self.inner.validate()?;
+        check_no_service_notification(self.inner.is_service_notification)
+    }
+
+    /// Returns the combination of flags that will be passed to the MessageBox api when the
+    /// message box is shown, without showing it. See [WinDialog::style_flags] for more
+    /// information.
+    pub fn style_flags(&self) -> MESSAGEBOX_STYLE {
+        self.inner.style_flags()
+    }
+
+    /// Returns the fully resolved parameters of the message box without showing it. See
+    /// [WinDialog::describe] for more information.
+    pub fn describe(&self) -> DialogDescriptor {
+        self.inner.descriptor(true)
+    }
+
     /// Display the message box.
     pub fn show(self) -> ShowReturn<T> {
-        let help_button = match self.show_help_button {
-            true => MB_HELP,
-            false => MESSAGEBOX_STYLE::default(),
-        };
+        self.inner.show_inner(self.window_handle)
+    }
+
+    /// Display the message box and convert the response with the closure. See
+    /// [WinDialog::show_map] for more information.
+    pub fn show_map<R>(self, f: impl FnOnce(T::Return) -> R) -> crate::Result<R> {
+        self.show().map(f)
+    }
+
+    /// Display the message box, closing it automatically if the user has not responded
+    /// within the given duration. See [WinDialog::show_with_timeout] for more information.
+    pub fn show_with_timeout(self, duration: Duration) -> crate::Result<Timed<T::Return>> {
+        Timed::from_result(self.with_duration(duration).show())
+    }
+
+    /// Display the message box and return the response along with how long it was open.
+    /// See [WinDialog::show_timed] for more information.
+    pub fn show_timed(self) -> crate::Result<(T::Return, Duration)> {
+        let start = Instant::now();
+        let response = self.show()?;
+        Ok((response, start.elapsed()))
+    }
+
+    /// Display the message box on a dedicated thread and return immediately. See
+    /// [WinDialog::show_detached] for more information.
+    ///
+    /// The parent window is not owned by the dialog, so it is up to the caller to ensure
+    /// that the window handle stays valid until the dialog thread has finished.
+    pub fn show_detached(self) -> DialogHandle<T>
+    where
+        T: Send + 'static,
+        T::Return: Send + 'static,
+    {
+        self.inner.detach(self.window_handle)
+    }
 
-        self.inner.show_inner(help_button)
+    /// Display the message box and convert the result into an [AnyResponse]. See
+    /// [WinDialog::show_any] for more information.
+    pub fn show_any(self) -> crate::Result<AnyResponse> {
+        AnyResponse::try_from(self.inner.show_raw(self.window_handle)?)
     }
 
     /// Indicate the modality of the dialog box. See [Modality] for the options.
@@ -395,6 +1999,20 @@ where
         self
     }
 
+    /// Indicate which button is selected by default, by its position in the message box.
+    /// See [WinDialog::set_default_button] for more information.
+    pub fn set_default_button(mut self, button: DefaultButton) -> Self {
+        self.inner.default_button = button;
+        self
+    }
+
+    /// Indicate which button is selected by default, by the response it produces.
+    /// See [WinDialog::set_default] for more information.
+    pub fn set_default(mut self, which: impl Into<DefaultChoice<T::Return>>) -> Self {
+        self.inner = self.inner.set_default(which);
+        self
+    }
+
     /// Same as desktop of the interactive window station. For more information, see
     /// [Window Stations](https://learn.microsoft.com/en-us/windows/win32/winstation/window-stations).
     /// If the current input desktop is not the default desktop, the Message Box does not return until the
@@ -404,18 +2022,65 @@ where
         self
     }
 
-    /// Set the text to right-justify style
+    /// Undo [WinDialogWithParent::set_default_desktop_only].
+    pub fn unset_default_desktop_only(mut self) -> Self {
+        self.inner.default_desktop_only = false;
+        self
+    }
+
+    /// Set the text to right-justify style. See [WinDialog::set_right_justify] for more
+    /// information.
     pub fn set_right_justify(mut self) -> Self {
         self.inner.right_justify_text = true;
         self
     }
 
+    /// Undo [WinDialogWithParent::set_right_justify], aligning the text to the left again.
+    pub fn unset_right_justify(mut self) -> Self {
+        self.inner.right_justify_text = false;
+        self
+    }
+
     /// Displays message and caption text using right-to-left reading order on Hebrew and Arabic systems.
     pub fn set_right_to_left_reading(mut self) -> Self {
         self.inner.right_to_left_reading = true;
         self
     }
 
+    /// Undo [WinDialogWithParent::set_right_to_left_reading], reading the text from left to right again.
+    pub fn unset_right_to_left_reading(mut self) -> Self {
+        self.inner.right_to_left_reading = false;
+        self
+    }
+
+    /// Lay the message box out for a right-to-left locale.
+    /// See [WinDialog::set_rtl_locale] for more information.
+    pub fn set_rtl_locale(mut self) -> Self {
+        self.inner = self.inner.set_rtl_locale();
+        self
+    }
+
+    /// Display the standard buttons in the given language. See [WinDialog::with_language]
+    /// for more information.
+    pub fn with_language(mut self, language: u16) -> Self {
+        self.inner = self.inner.with_language(language);
+        self
+    }
+
+    /// Pass additional `MB_*` flags to the MessageBox api. See [WinDialog::with_raw_flags]
+    /// for more information.
+    pub fn with_raw_flags(mut self, flags: MESSAGEBOX_STYLE) -> Self {
+        self.inner.raw_flags |= flags;
+        self
+    }
+
+    /// Lay the message box out for a right-to-left locale if the user interface language
+    /// is Hebrew or Arabic. See [WinDialog::set_rtl_auto] for more information.
+    pub fn set_rtl_auto(mut self) -> Self {
+        self.inner = self.inner.set_rtl_auto();
+        self
+    }
+
     /// The message box becomes the foreground window. Internally, the system calls the
     /// [SetForegroundWindow](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setforegroundwindow) function for the message box.
     pub fn set_foreground(mut self) -> Self {
@@ -423,148 +2088,299 @@ where
         self
     }
 
+    /// Undo [WinDialogWithParent::set_foreground].
+    pub fn unset_foreground(mut self) -> Self {
+        self.inner.foreground = false;
+        self
+    }
+
     /// The message box is created with the WS_EX_TOPMOST window style.
     pub fn set_topmost(mut self) -> Self {
         self.inner.topmost = true;
         self
     }
 
+    /// Undo [WinDialogWithParent::set_topmost].
+    pub fn unset_topmost(mut self) -> Self {
+        self.inner.topmost = false;
+        self
+    }
+
+    /// Make the message box a service notification. A service notification cannot have a
+    /// parent window, so showing the message box fails with
+    /// [crate::Error::InvalidConfiguration] unless the parent is removed with
+    /// [WinDialogWithParent::remove_parent]. See [WinDialog::make_service_notification] for
+    /// more information.
+    pub fn make_service_notification(mut self) -> Self {
+        self.inner.is_service_notification = true;
+        self
+    }
+
+    /// Undo [WinDialogWithParent::make_service_notification].
+    pub fn unset_service_notification(mut self) -> Self {
+        self.inner.is_service_notification = false;
+        self
+    }
+
+    /// Detach the message box from its parent window, keeping every other option,
+    /// including [WinDialogWithParent::make_service_notification].
+    ///
+    /// ```rust
+    /// use win_dialog::WinDialog;
+    /// use windows::Win32::Foundation::HWND;
+    ///
+    /// let dialog = WinDialog::new("The disk is full")
+    ///     .make_service_notification()
+    ///     .set_parent_window(HWND(1));
+    /// assert!(dialog.validate().is_err());
+    /// assert!(dialog.remove_parent().validate().is_ok());
+    /// ```
+    pub fn remove_parent(self) -> WinDialog<T> {
+        self.inner
+    }
+
+    /// Turn off all of the options that are switched on with the `set_` methods. See
+    /// [WinDialog::reset_flags] for more information.
+    pub fn reset_flags(mut self) -> Self {
+        self.inner = self.inner.reset_flags();
+        self
+    }
+
+    /// Close the message box automatically if the user has not responded within the given
+    /// duration. See [WinDialog::with_duration] for more information.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.inner.duration = Some(duration);
+        self
+    }
+
+    /// Play a `.wav` file while the message box is displayed. See [WinDialog::with_sound] for
+    /// more information.
+    pub fn with_sound(mut self, path: impl Into<PathBuf>, loop_until_closed: bool) -> Self {
+        self.inner.sound = Some(Sound::new(path.into(), loop_until_closed));
+        self
+    }
+
+    /// Remove any nul characters from the content and header instead of failing. See
+    /// [WinDialog::with_lossy_content] for more information.
+    pub fn with_lossy_content(mut self) -> Self {
+        self.inner.lossy_content = true;
+        self
+    }
+
+    /// Break the lines of the content at the given number of characters. See
+    /// [WinDialog::wrap_at] for more information.
+    pub fn wrap_at(mut self, columns: usize) -> Self {
+        self.inner.wrap_columns = Some(columns);
+        self
+    }
+
+    /// Set the length of content above which the dialog is not displayed as a message box.
+    /// See [WinDialog::with_content_limit] for more information.
+    pub fn with_content_limit(mut self, limit: usize) -> Self {
+        self.inner.content_limit = Some(limit);
+        self
+    }
+
+    /// Display content that is longer than the limit in a task dialog instead of failing.
+    /// See [WinDialog::allow_long_content] for more information.
+    pub fn allow_long_content(mut self) -> Self {
+        self.inner.long_content = true;
+        self
+    }
+
     /// Indicate which set of actions that you want the user to have. Check the available
-    /// options in [crate::style].
+    /// options in [crate::style]. All other options, and the parent window, are kept.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use win_dialog::{DefaultButton, DialogStyle, Icon, Modality};
+    /// use win_dialog::{OkCancel, WinDialog, WinDialogWithParent, YesNo};
+    /// use windows::Win32::Foundation::HWND;
+    /// # use windows::Win32::UI::WindowsAndMessaging::MB_NOFOCUS;
+    ///
+    /// fn configure<T: DialogStyle>(dialog: WinDialogWithParent<T>) -> WinDialogWithParent<T> {
+    ///     // ...
+    /// #   dialog
+    /// #       .with_header("Header")
+    /// #       .with_instruction("Instruction")
+    /// #       .with_alert(Icon::Warning, false)
+    /// #       .with_help_button()
+    /// #       .set_modality(Modality::Task)
+    /// #       .set_default_button(DefaultButton::Second)
+    /// #       .set_default_desktop_only()
+    /// #       .set_right_justify()
+    /// #       .set_right_to_left_reading()
+    /// #       .set_foreground()
+    /// #       .set_topmost()
+    /// #       .make_service_notification()
+    /// #       .with_duration(Duration::from_secs(5))
+    /// #       .with_sound("alert.wav", true)
+    /// #       .with_lossy_content()
+    /// #       .with_content_limit(100)
+    /// #       .allow_long_content()
+    /// #       .with_language(0x040D)
+    /// #       .with_raw_flags(MB_NOFOCUS)
+    /// #       .wrap_at(40)
+    /// }
+    ///
+    /// let dialog = WinDialog::new("Content").set_parent_window(HWND(1));
+    /// let before = configure(dialog.clone().with_style(OkCancel)).with_style(YesNo);
+    /// let after = configure(dialog.with_style(YesNo));
+    /// assert_eq!(before, after);
+    /// ```
     pub fn with_style<N>(self, style: N) -> WinDialogWithParent<N>
     where
         N: DialogStyle,
     {
         WinDialogWithParent {
-            inner: WinDialog::<N> {
-                header: self.inner.header,
-                content: self.inner.content,
-                style,
-                topmost: self.inner.topmost,
-                is_service_notification: false,
-                right_to_left_reading: self.inner.right_to_left_reading,
-                modality: self.inner.modality,
-                icon: self.inner.icon,
-                default_button: self.inner.default_button,
-                default_desktop_only: self.inner.default_desktop_only,
-                right_justify_text: self.inner.right_justify_text,
-                foreground: self.inner.foreground,
-            },
+            inner: self.inner.with_style(style),
             window_handle: self.window_handle,
-            show_help_button: self.show_help_button,
         }
     }
+
+    /// Splits the message box into its configuration and the parent window.
+    pub(crate) fn into_parts(self) -> (WinDialog<T>, HWND) {
+        (self.inner, self.window_handle)
+    }
+}
+
+impl WinDialogWithParent<Ok_> {
+    /// Display the dialog and wait for the user to dismiss it, ignoring the response.
+    /// See [WinDialog::notify] for more information.
+    pub fn notify(self) {
+        let _ = self.show();
+    }
 }
 
 impl WinDialogWithParent<OkCancel> {
-    /// Sets the help button as default. Will do nothing if [WinDialogWithParent::with_help_button] has not
-    /// been called.
-    pub fn set_default_help(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON3;
-        self
+    /// Sets the help button as default. If [WinDialogWithParent::with_help_button] has not
+    /// been called, the first button is the default instead.
+    pub fn set_default_help(self) -> Self {
+        self.set_default(DefaultChoice::Help)
     }
 
     /// Make [crate::style::OkCancelResponse::Cancel] the default response.
-    pub fn set_default_cancel(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_cancel(self) -> Self {
+        self.set_default(OkCancelResponse::Cancel)
     }
 }
 
 impl WinDialogWithParent<AbortRetryIgnore> {
-    /// Sets the help button as default. Will do nothing if [WinDialogWithParent::with_help_button] has not
-    /// been called.
-    pub fn set_default_help(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON4;
-        self
+    /// Run a fallible operation, and show the message box with the error each time it
+    /// fails. See `show_retrying` on `WinDialog<AbortRetryIgnore>` for more information.
+    pub fn show_retrying<R, E>(
+        self,
+        operation: impl FnMut() -> Result<R, E>,
+    ) -> Result<Option<R>, RetryError<E>>
+    where
+        E: Display,
+    {
+        self.inner.retrying(self.window_handle, operation)
+    }
+
+    /// Sets the help button as default. If [WinDialogWithParent::with_help_button] has not
+    /// been called, the first button is the default instead.
+    pub fn set_default_help(self) -> Self {
+        self.set_default(DefaultChoice::Help)
     }
 
     /// Make [crate::style::AbortRetryIgnoreResponse::Retry] the default response.
-    pub fn set_default_retry(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_retry(self) -> Self {
+        self.set_default(AbortRetryIgnoreResponse::Retry)
     }
 
     /// Make [crate::style::AbortRetryIgnoreResponse::Ignore] the default response.
-    pub fn set_default_ignore(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON3;
-        self
+    pub fn set_default_ignore(self) -> Self {
+        self.set_default(AbortRetryIgnoreResponse::Ignore)
     }
 }
 
 impl WinDialogWithParent<YesNoCancel> {
-    /// Sets the help button as default. Will do nothing if [WinDialogWithParent::with_help_button] has not
-    /// been called.
-    pub fn set_default_help(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON4;
-        self
+    /// Sets the help button as default. If [WinDialogWithParent::with_help_button] has not
+    /// been called, the first button is the default instead.
+    pub fn set_default_help(self) -> Self {
+        self.set_default(DefaultChoice::Help)
     }
 
     /// Make [crate::style::YesNoCancelResponse::No] the default response.
-    pub fn set_default_no(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_no(self) -> Self {
+        self.set_default(YesNoCancelResponse::No)
     }
 
     /// Make [crate::style::YesNoCancelResponse::Cancel] the default response.
-    pub fn set_default_cancel(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON3;
-        self
+    pub fn set_default_cancel(self) -> Self {
+        self.set_default(YesNoCancelResponse::Cancel)
     }
 }
 
 impl WinDialogWithParent<YesNo> {
-    /// Sets the help button as default. Will do nothing if [WinDialogWithParent::with_help_button] has not
-    /// been called.
-    pub fn set_default_help(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON3;
-        self
+    /// Sets the help button as default. If [WinDialogWithParent::with_help_button] has not
+    /// been called, the first button is the default instead.
+    pub fn set_default_help(self) -> Self {
+        self.set_default(DefaultChoice::Help)
     }
 
     /// Make [crate::style::YesNoResponse::No] the default response.
-    pub fn set_default_no(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_no(self) -> Self {
+        self.set_default(YesNoResponse::No)
     }
 }
 
 impl WinDialogWithParent<RetryCancel> {
-    /// Sets the help button as default. Will do nothing if [WinDialogWithParent::with_help_button] has not
-    /// been called.
-    pub fn set_default_help(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON3;
-        self
+    /// Run a fallible operation, and show the message box with the error each time it
+    /// fails. See `show_retrying` on `WinDialog<RetryCancel>` for more information.
+    pub fn show_retrying<R, E>(
+        self,
+        operation: impl FnMut() -> Result<R, E>,
+    ) -> Result<Option<R>, RetryError<E>>
+    where
+        E: Display,
+    {
+        self.inner.retrying(self.window_handle, operation)
+    }
+
+    /// Sets the help button as default. If [WinDialogWithParent::with_help_button] has not
+    /// been called, the first button is the default instead.
+    pub fn set_default_help(self) -> Self {
+        self.set_default(DefaultChoice::Help)
     }
 
     /// Make [crate::style::RetryCancelResponse::Cancel] the default response.
-    pub fn set_default_cancel(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_cancel(self) -> Self {
+        self.set_default(RetryCancelResponse::Cancel)
     }
 }
 
 impl WinDialogWithParent<CancelRetryContinue> {
+    /// Run a fallible operation, and show the message box with the error each time it
+    /// fails. See `show_retrying` on `WinDialog<CancelRetryContinue>` for more information.
+    pub fn show_retrying<R, E>(
+        self,
+        operation: impl FnMut() -> Result<R, E>,
+    ) -> Result<Option<R>, RetryError<E>>
+    where
+        E: Display,
+    {
+        self.inner.retrying(self.window_handle, operation)
+    }
+
     /// Set the default button to cancel.
-    pub fn set_default_cancel(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON1;
-        self
+    pub fn set_default_cancel(self) -> Self {
+        self.set_default(CancelRetryContinueResponse::Cancel)
     }
 
     /// Set the default button to help.
-    pub fn set_default_help(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON4;
-        self
+    pub fn set_default_help(self) -> Self {
+        self.set_default(DefaultChoice::Help)
     }
 
     /// Set the default button to retry.
-    pub fn set_default_retry(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON2;
-        self
+    pub fn set_default_retry(self) -> Self {
+        self.set_default(CancelRetryContinueResponse::Retry)
     }
 
     /// Set the default button to continue.
-    pub fn set_default_continue(mut self) -> Self {
-        self.inner.default_button = MB_DEFBUTTON3;
-        self
+    pub fn set_default_continue(self) -> Self {
+        self.set_default(CancelRetryContinueResponse::Continue)
     }
 }
@@ -0,0 +1,141 @@
+use std::fmt::Debug;
+
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::WindowsAndMessaging::MESSAGEBOX_STYLE;
+
+use crate::describe::DialogDescriptor;
+use crate::dialog::{AnyResponse, WinDialog, WinDialogWithParent};
+use crate::style::{DialogStyle, Ok_};
+
+/// A style that can be chosen at runtime, for example from a [Box]. Implemented for every
+/// [DialogStyle], see [WinDialog::with_dyn_style].
+pub trait DynStyle: Debug + Send + Sync {
+    /// Gives the dialog this style and erases it into a [DynDialog].
+    fn erase(&self, dialog: WinDialog<Ok_>) -> DynDialog;
+}
+
+impl<T> DynStyle for T
+where
+    T: DialogStyle + Debug + Send + Sync + 'static,
+{
+    fn erase(&self, dialog: WinDialog<Ok_>) -> DynDialog {
+        DynDialog::from(dialog.with_style(*self))
+    }
+}
+
+/// The operations of a dialog that do not depend on the type of its style.
+trait ErasedDialog: Debug + Send + Sync {
+    /// Displays the dialog for the owner window and converts the result.
+    fn show_any(self: Box<Self>, owner: HWND) -> crate::Result<AnyResponse>;
+    /// Returns the resolved parameters of the dialog.
+    fn describe(&self, has_parent: bool) -> DialogDescriptor;
+    /// Returns the flags passed to the MessageBox api.
+    fn style_flags(&self) -> MESSAGEBOX_STYLE;
+    /// Clones the dialog into a new box.
+    fn clone_box(&self) -> Box<dyn ErasedDialog>;
+}
+
+impl<T> ErasedDialog for WinDialog<T>
+where
+    T: DialogStyle + Debug + Send + Sync + 'static,
+{
+    fn show_any(self: Box<Self>, owner: HWND) -> crate::Result<AnyResponse> {
+        AnyResponse::try_from(self.show_raw(owner)?)
+    }
+
+    fn describe(&self, has_parent: bool) -> DialogDescriptor {
+        self.descriptor(has_parent)
+    }
+
+    fn style_flags(&self) -> MESSAGEBOX_STYLE {
+        WinDialog::style_flags(self)
+    }
+
+    fn clone_box(&self) -> Box<dyn ErasedDialog> {
+        Box::new(self.clone())
+    }
+}
+
+/// A dialog whose style is only known at runtime. Since the style is erased, the response
+/// is returned as an [AnyResponse]. Any [WinDialog] or [WinDialogWithParent] converts into
+/// a [DynDialog], so dialogs of different styles can be chosen between in the same branch:
+///
+/// ```rust
+/// use win_dialog::{DynDialog, DynStyle, OkCancel, WinDialog, YesNo};
+///
+/// let destructive = true;
+///
+/// let style: Box<dyn DynStyle> = match destructive {
+///     true => Box::new(YesNo),
+///     false => Box::new(OkCancel),
+/// };
+/// let dialog = WinDialog::new("Apply the changes?").with_dyn_style(style.as_ref());
+/// assert_eq!(dialog.describe().style, "YesNo");
+///
+/// let dialog: DynDialog = match destructive {
+///     true => WinDialog::new("Delete the file?").with_style(YesNo).into(),
+///     false => WinDialog::new("Save the file?").with_style(OkCancel).into(),
+/// };
+/// assert_eq!(dialog.describe().buttons, ["Yes", "No"]);
+/// ```
+#[derive(Debug)]
+pub struct DynDialog {
+    /// The dialog, with its style erased.
+    inner: Box<dyn ErasedDialog>,
+    /// The parent window, if the dialog was converted from a [WinDialogWithParent].
+    owner: Option<HWND>,
+}
+
+impl DynDialog {
+    /// Display the dialog and convert the result into an [AnyResponse]. This is a
+    /// synchronous action.
+    pub fn show(self) -> crate::Result<AnyResponse> {
+        self.inner.show_any(self.owner.unwrap_or_default())
+    }
+
+    /// Returns the fully resolved parameters of the dialog without showing it. See
+    /// [WinDialog::describe] for more information.
+    pub fn describe(&self) -> DialogDescriptor {
+        self.inner.describe(self.owner.is_some())
+    }
+
+    /// Returns the combination of flags that will be passed to the MessageBox api when the
+    /// dialog is shown. See [WinDialog::style_flags] for more information.
+    pub fn style_flags(&self) -> MESSAGEBOX_STYLE {
+        self.inner.style_flags()
+    }
+}
+
+impl Clone for DynDialog {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone_box(),
+            owner: self.owner,
+        }
+    }
+}
+
+impl<T> From<WinDialog<T>> for DynDialog
+where
+    T: DialogStyle + Debug + Send + Sync + 'static,
+{
+    fn from(dialog: WinDialog<T>) -> Self {
+        Self {
+            inner: Box::new(dialog),
+            owner: None,
+        }
+    }
+}
+
+impl<T> From<WinDialogWithParent<T>> for DynDialog
+where
+    T: DialogStyle + Debug + Send + Sync + 'static,
+{
+    fn from(dialog: WinDialogWithParent<T>) -> Self {
+        let (dialog, owner) = dialog.into_parts();
+        Self {
+            inner: Box::new(dialog),
+            owner: Some(owner),
+        }
+    }
+}
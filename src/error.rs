@@ -1,4 +1,5 @@
-use std::ffi::NulError;
+use windows::core::HRESULT;
+use windows::Win32::Foundation::WIN32_ERROR;
 
 /// The possible errors that could occur when showing the message
 /// box.
@@ -9,8 +10,136 @@ pub enum Error {
     #[error("Dialog returned unknown response code: {0}")]
     UnknownResponseCode(i32),
 
-    /// This error occurs in converting an input string to the [std::ffi::CString]
-    /// representation that Windows expects fails.
-    #[error("String could not be converted to C-string: {0}")]
-    InvalidString(#[from] NulError),
+    /// The same as [Error::UnknownResponseCode], but returned when showing a dialog of a
+    /// known style, which is included to make the cause easier to track down.
+    #[error("Dialog of style {style} returned unknown response code: {code}")]
+    UnknownResponseCodeForStyle {
+        /// The code returned by Windows.
+        code: i32,
+        /// The name of the style of the dialog, see [crate::DialogStyle::NAME].
+        style: &'static str,
+    },
+
+    /// Windows returned a response code that it is documented to return, but that does not
+    /// belong to any of the buttons of the style of the dialog, such as `IDCONTINUE` for a
+    /// [crate::YesNo] dialog.
+    #[error(
+        "Dialog of style {style} returned response code {code}, which belongs to another style"
+    )]
+    UnexpectedResponseForStyle {
+        /// The code returned by Windows.
+        code: i32,
+        /// The name of the style of the dialog, see [crate::DialogStyle::NAME].
+        style: &'static str,
+    },
+
+    /// Windows failed to display the message box, for example because of an invalid
+    /// owner window or insufficient resources. Contains the code reported by
+    /// [GetLastError](https://learn.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-getlasterror).
+    #[error("Dialog could not be displayed: {}", system_message(.0.to_hresult()))]
+    SystemError(WIN32_ERROR),
+
+    /// Windows failed to display the task dialog, for example because version 6 of the
+    /// Common Controls library was not loaded. Contains the code returned by
+    /// [TaskDialogIndirect](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-taskdialogindirect).
+    #[error("Task dialog could not be displayed: {}", system_message(*.0))]
+    TaskDialogError(HRESULT),
+
+    /// Message boxes can only be displayed on Windows. This error is returned when
+    /// showing a dialog on any other platform, which allows crates that depend on this
+    /// one to build and run their tests everywhere.
+    #[error("Dialogs are only supported on Windows")]
+    Unsupported,
+
+    /// The window handle passed to `set_parent_raw_window_handle` does not belong to a
+    /// Win32 window, so it cannot be the parent of a message box. The variant exists
+    /// without the `raw-window-handle` feature as well, so that enabling it does not break
+    /// exhaustive matches, but it is never returned then.
+    #[error("Only Win32 window handles can be used as the parent of a dialog")]
+    UnsupportedWindowHandle,
+
+    /// A dialog was shown with the `mock` feature enabled, but no response was injected
+    /// with `set_mock_response` on the current thread. The variant exists without the
+    /// feature as well, so that enabling it does not break exhaustive matches, but it is
+    /// never returned then.
+    #[error("No mock response was set for the current thread")]
+    NoMockResponse,
+
+    /// The dialog passed to `show_async` was never shown, because the tokio runtime shut
+    /// down first. The variant exists without the `async-tokio` feature as well, so that
+    /// enabling it does not break exhaustive matches, but it is never returned then.
+    #[error("The dialog was not shown because the runtime shut down")]
+    RuntimeShutdown,
+
+    /// The message box was closed because the duration set with
+    /// [crate::WinDialog::with_duration] elapsed before the user responded.
+    #[error("Dialog timed out before the user responded")]
+    Timeout,
+
+    /// This error occurs when an input string contains a nul character, which
+    /// cannot be represented in the nul-terminated UTF-16 string that Windows expects.
+    /// Contains the position of the offending character in the encoded string.
+    #[error("String contains an interior nul character at position {0}")]
+    InvalidString(usize),
+
+    /// The sound file set with [crate::WinDialog::with_sound] does not exist, or Windows
+    /// could not play it. Contains the path of the file.
+    #[error("Sound file {} could not be played", .0.display())]
+    InvalidSound(std::path::PathBuf),
+
+    /// The content of a message box is longer than it can reliably display, so Windows
+    /// would cut it off. Contains the length of the content in UTF-16 code units. See
+    /// [crate::WinDialog::allow_long_content] to display such content in a task dialog
+    /// instead.
+    #[error("Content of {0} characters is too long for a message box")]
+    ContentTooLong(usize),
+
+    /// The dialog was configured with options that conflict with each other. Returned by
+    /// [crate::WinDialog::validate], when showing a service notification that has a
+    /// parent window, and when showing a dialog with a timeout as a task dialog. Contains a
+    /// description of the conflict.
+    #[error("Invalid dialog configuration: {0}")]
+    InvalidConfiguration(String),
+
+    /// A name parsed from a string, such as the name of an [crate::Icon] or a
+    /// [crate::Modality], is not one of the recognized names.
+    #[error("Unknown {kind} name: {name:?}")]
+    UnknownName {
+        /// What the name was supposed to denote, such as `"icon"`.
+        kind: &'static str,
+        /// The name that was not recognized.
+        name: String,
+    },
+}
+
+/// Returned by helpers such as [crate::style::YesNoResponse::require_yes] when the user
+/// did not give the response that the program needs in order to continue. Contains the
+/// response that was actually given.
+#[derive(Debug, Clone, Copy, PartialEq, thiserror::Error)]
+#[error("The user declined the dialog with response {0:?}")]
+pub struct UserDeclined<R: std::fmt::Debug>(pub R);
+
+/// Returned by `show_retrying` on dialogs that offer to retry a failed operation, such as
+/// [crate::WinDialog] with the [crate::AbortRetryIgnore] style.
+#[derive(Debug, thiserror::Error)]
+pub enum RetryError<E> {
+    /// The user gave up on the operation. Contains the error of its last attempt.
+    #[error("{0}")]
+    Aborted(E),
+
+    /// The dialog asking the user what to do could not be shown.
+    #[error(transparent)]
+    Dialog(#[from] Error),
+}
+
+/// Formats a Windows error code alongside the system's description of it.
+#[cfg(windows)]
+fn system_message(code: HRESULT) -> String {
+    format!("{} (code {:#010X})", code.message(), code.0)
+}
+
+/// The system's description of an error code is only available on Windows.
+#[cfg(not(windows))]
+fn system_message(code: HRESULT) -> String {
+    format!("code {:#010X}", code.0)
 }
@@ -0,0 +1,148 @@
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+
+use windows::Win32::Foundation::HWND;
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::Foundation::{LPARAM, WPARAM};
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::UI::WindowsAndMessaging::{PostMessageW, WM_CLOSE};
+
+use crate::style::DialogStyle;
+
+/// A handle to a dialog that is being displayed on a dedicated thread. Returned by
+/// [crate::WinDialog::show_detached] and [crate::WinDialogWithParent::show_detached].
+///
+/// Dropping the handle does not close the dialog; the thread keeps running until the
+/// user responds, but the response is discarded.
+#[derive(Debug)]
+pub struct DialogHandle<T>
+where
+    T: DialogStyle,
+{
+    /// The thread on which the dialog is displayed.
+    thread: JoinHandle<crate::Result<T::Return>>,
+    /// The window of the dialog, shared with the thread that displays it.
+    window: Arc<DialogWindow>,
+}
+
+impl<T> DialogHandle<T>
+where
+    T: DialogStyle,
+{
+    /// Wraps the thread that is displaying the dialog.
+    pub(crate) fn new(
+        thread: JoinHandle<crate::Result<T::Return>>,
+        window: Arc<DialogWindow>,
+    ) -> Self {
+        Self { thread, window }
+    }
+
+    /// Returns true once the user has responded to the dialog and [DialogHandle::join]
+    /// will no longer block.
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Blocks until the dialog window has been created and returns it, so that it can be
+    /// manipulated from another thread, for example to dismiss it with `WM_CLOSE`. Returns
+    /// [None] if the dialog has already closed or could not be displayed.
+    ///
+    /// The handle is only valid while the dialog is open. The dialog can close at any
+    /// moment, after which Windows may reuse the handle for an unrelated window, so
+    /// anything sent to it races with the user's response.
+    pub fn window(&self) -> Option<HWND> {
+        self.window.wait()
+    }
+
+    /// Asks the dialog to close as if the user had pressed its close button, and returns
+    /// whether the request was sent. Message boxes that cannot be closed this way, such as
+    /// those without a 'cancel' button, ignore the request. The same races as described in
+    /// [DialogHandle::window] apply.
+    pub fn close(&self) -> bool {
+        match self.window() {
+            #[cfg(all(windows, not(feature = "mock")))]
+            Some(window) => unsafe { PostMessageW(window, WM_CLOSE, WPARAM(0), LPARAM(0)) }.is_ok(),
+            _ => false,
+        }
+    }
+
+    /// Blocks the current thread until the user responds to the dialog and returns the
+    /// response. If the dialog thread panicked, the panic is propagated to the caller.
+    pub fn join(self) -> crate::Result<T::Return> {
+        match self.thread.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+}
+
+/// The lifecycle of the window of a dialog displayed on another thread.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum WindowState {
+    /// The dialog has not appeared yet.
+    #[default]
+    Pending,
+    /// The dialog is displayed in this window.
+    Open(HWND),
+    /// The dialog has closed, or never appeared.
+    Closed,
+}
+
+/// Shares the window of a detached dialog between the thread that displays it and the
+/// [DialogHandle], which may wait for it to appear.
+#[derive(Debug, Default)]
+pub(crate) struct DialogWindow {
+    /// The current state of the window.
+    state: Mutex<WindowState>,
+    /// Notified whenever the state changes.
+    changed: Condvar,
+}
+
+impl DialogWindow {
+    /// Moves to a new state and wakes any waiting threads.
+    fn set(&self, state: WindowState) {
+        // The state is a plain value, so it is still consistent if a thread panicked.
+        *self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = state;
+        self.changed.notify_all();
+    }
+
+    /// Records that the dialog appeared in the given window.
+    pub(crate) fn open(&self, window: HWND) {
+        self.set(WindowState::Open(window));
+    }
+
+    /// Records that the dialog closed. Called when the dialog thread finishes, even if
+    /// it panicked, so that waiting threads are never blocked forever.
+    pub(crate) fn close(&self) {
+        self.set(WindowState::Closed);
+    }
+
+    /// Blocks until the dialog has either appeared or closed.
+    fn wait(&self) -> Option<HWND> {
+        let state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let state = self
+            .changed
+            .wait_while(state, |state| *state == WindowState::Pending)
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        match *state {
+            WindowState::Open(window) => Some(window),
+            _ => None,
+        }
+    }
+}
+
+/// Closes the shared window when dropped, which happens however the dialog thread ends.
+pub(crate) struct CloseOnDrop(pub(crate) Arc<DialogWindow>);
+
+impl Drop for CloseOnDrop {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
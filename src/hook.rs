@@ -0,0 +1,91 @@
+#[cfg(all(windows, not(feature = "mock")))]
+use std::cell::RefCell;
+
+use windows::Win32::Foundation::HWND;
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::Foundation::{HINSTANCE, LPARAM, LRESULT, WPARAM};
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::System::Threading::GetCurrentThreadId;
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::UI::WindowsAndMessaging::{
+    CallNextHookEx, GetClassNameW, SetWindowsHookExW, UnhookWindowsHookEx, HCBT_ACTIVATE, HHOOK,
+    WH_CBT,
+};
+
+/// The window class that Windows uses for message boxes and other dialogs.
+#[cfg(all(windows, not(feature = "mock")))]
+const DIALOG_CLASS: &str = "#32770";
+
+/// A callback receiving the window of a newly created dialog.
+#[cfg(all(windows, not(feature = "mock")))]
+type OnCreated = Box<dyn FnOnce(HWND)>;
+
+#[cfg(all(windows, not(feature = "mock")))]
+thread_local! {
+    /// Invoked with the window of the next dialog that is activated on this thread.
+    static ON_CREATED: RefCell<Option<OnCreated>> = RefCell::new(None);
+}
+
+/// Returns true if the window belongs to the dialog window class.
+#[cfg(all(windows, not(feature = "mock")))]
+fn is_dialog(window: HWND) -> bool {
+    let mut class = [0u16; 16];
+    let length = unsafe { GetClassNameW(window, &mut class) };
+    usize::try_from(length)
+        .map(|length| String::from_utf16_lossy(&class[..length]) == DIALOG_CLASS)
+        .unwrap_or(false)
+}
+
+/// Computer-based training hook, which Windows calls before activating a window on the
+/// thread. Message boxes offer no other way to obtain their window.
+#[cfg(all(windows, not(feature = "mock")))]
+unsafe extern "system" fn hook(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    if code == HCBT_ACTIVATE as i32 {
+        let window = HWND(wparam.0 as isize);
+        if is_dialog(window) {
+            if let Some(on_created) = ON_CREATED.with(|callback| callback.borrow_mut().take()) {
+                on_created(window);
+            }
+        }
+    }
+
+    CallNextHookEx(HHOOK::default(), code, wparam, lparam)
+}
+
+/// Runs `show` while watching the current thread for the dialog it displays, and invokes
+/// `on_created` with the window of the dialog as soon as it appears. `on_created` is called
+/// at most once, and not at all if the dialog fails to appear.
+#[cfg(all(windows, not(feature = "mock")))]
+pub(crate) fn on_dialog_created<R>(
+    on_created: impl FnOnce(HWND) + 'static,
+    show: impl FnOnce() -> R,
+) -> R {
+    ON_CREATED.with(|callback| *callback.borrow_mut() = Some(Box::new(on_created)));
+    let hook = unsafe {
+        SetWindowsHookExW(
+            WH_CBT,
+            Some(hook),
+            HINSTANCE::default(),
+            GetCurrentThreadId(),
+        )
+    };
+
+    let result = show();
+
+    if let Ok(hook) = hook {
+        let _ = unsafe { UnhookWindowsHookEx(hook) };
+    }
+    ON_CREATED.with(|callback| callback.borrow_mut().take());
+
+    result
+}
+
+/// Without Windows, or with the `mock` feature enabled, no dialog window is ever created,
+/// so `on_created` is never called.
+#[cfg(any(not(windows), feature = "mock"))]
+pub(crate) fn on_dialog_created<R>(
+    _on_created: impl FnOnce(HWND) + 'static,
+    show: impl FnOnce() -> R,
+) -> R {
+    show()
+}
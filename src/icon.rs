@@ -1,3 +1,15 @@
+// The deprecation of Icon::Question is aimed at users of the crate; the crate itself
+// still has to refer to the variant in its conversions and derived impls.
+#![cfg_attr(feature = "deprecated", allow(deprecated))]
+
+use std::str::FromStr;
+
+use windows::core::PCWSTR;
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::Foundation::{GetLastError, BOOL};
+use windows::Win32::UI::Controls::{
+    TD_ERROR_ICON, TD_INFORMATION_ICON, TD_SHIELD_ICON, TD_WARNING_ICON,
+};
 use windows::Win32::UI::WindowsAndMessaging::{
     MB_ICONASTERISK, MB_ICONERROR, MB_ICONEXCLAMATION, MB_ICONHAND, MB_ICONINFORMATION,
     MB_ICONSTOP, MB_ICONWARNING, MESSAGEBOX_STYLE,
@@ -7,7 +19,8 @@ use windows::Win32::UI::WindowsAndMessaging::{
 use windows::Win32::UI::WindowsAndMessaging::MB_ICONQUESTION;
 
 /// Represents the set of icons available for a message box.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Icon {
     /// An exclamation point in a yellow triangle.
     Exclamation,
@@ -39,6 +52,10 @@ pub enum Icon {
     /// Despite the name, this is an alias for [Icon::Stop]. It does not
     /// display a hand. This is an idiosyncrasy of Windows.
     Hand,
+    /// A shield in the colors of the Windows logo, displayed in prompts for actions that
+    /// require administrator privileges. Only task dialogs can display it; message boxes
+    /// display [Icon::Information] instead.
+    Shield,
 }
 
 impl From<Icon> for MESSAGEBOX_STYLE {
@@ -53,6 +70,147 @@ impl From<Icon> for MESSAGEBOX_STYLE {
             Icon::Stop => MB_ICONSTOP,
             Icon::Error => MB_ICONERROR,
             Icon::Hand => MB_ICONHAND,
+            // Message boxes have no shield icon, the information icon is the closest match.
+            Icon::Shield => MB_ICONINFORMATION,
+        }
+    }
+}
+
+/// Converts the icon to the identifier of the matching task dialog icon, such as
+/// [TD_WARNING_ICON], which can be passed in a `TASKDIALOGCONFIG`. This is how
+/// [crate::WinTaskDialog] displays an [Icon].
+impl From<Icon> for PCWSTR {
+    fn from(value: Icon) -> Self {
+        match value {
+            Icon::Exclamation | Icon::Warning => TD_WARNING_ICON,
+            Icon::Information | Icon::Asterisk => TD_INFORMATION_ICON,
+            Icon::Stop | Icon::Error | Icon::Hand => TD_ERROR_ICON,
+            Icon::Shield => TD_SHIELD_ICON,
+            // Task dialogs have no question mark icon, so it is omitted.
+            #[cfg(feature = "deprecated")]
+            Icon::Question => PCWSTR::null(),
+        }
+    }
+}
+
+impl Icon {
+    /// How severe the message conveyed by the icon is, from `0` for informative messages
+    /// up to `2` for errors. Aliases display the same icon and so share a level:
+    ///
+    /// - `0`: [Icon::Information] and [Icon::Asterisk]
+    /// - `1`: [Icon::Exclamation], [Icon::Warning] and [Icon::Shield]
+    /// - `2`: [Icon::Stop], [Icon::Error] and [Icon::Hand]
+    ///
+    /// The deprecated `Icon::Question` is treated as informative. Because aliases compare
+    /// equal in severity but not with `==`, [Icon] does not implement an ordering itself.
+    /// Pick the most severe of several icons with [Iterator::max_by_key] instead:
+    ///
+    /// ```
+    /// use win_dialog::Icon;
+    ///
+    /// let icons = [Icon::Information, Icon::Error, Icon::Warning];
+    /// let worst = icons.iter().max_by_key(|icon| icon.severity());
+    /// assert_eq!(worst, Some(&Icon::Error));
+    /// ```
+    pub fn severity(&self) -> u8 {
+        match self {
+            Icon::Information | Icon::Asterisk => 0,
+            #[cfg(feature = "deprecated")]
+            Icon::Question => 0,
+            Icon::Exclamation | Icon::Warning | Icon::Shield => 1,
+            Icon::Stop | Icon::Error | Icon::Hand => 2,
         }
     }
+
+    /// Looks up an icon by the name of its variant, ignoring case, such as `"warning"` for
+    /// [Icon::Warning], or by the short name `"info"` for [Icon::Information]. Returns
+    /// [None] for unknown names. `"question"` is only recognized
+    /// when the `deprecated` feature is enabled, see [question_icon_available], so code
+    /// that selects icons from configuration needs no `#[cfg]` of its own.
+    ///
+    /// ```
+    /// use win_dialog::Icon;
+    ///
+    /// assert_eq!(Icon::from_name("Warning"), Some(Icon::Warning));
+    /// assert_eq!(Icon::from_name("siren"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Icon> {
+        let icon = match name.to_ascii_lowercase().as_str() {
+            "exclamation" => Icon::Exclamation,
+            "warning" => Icon::Warning,
+            "information" | "info" => Icon::Information,
+            "asterisk" => Icon::Asterisk,
+            #[cfg(feature = "deprecated")]
+            "question" => Icon::Question,
+            "stop" => Icon::Stop,
+            "error" => Icon::Error,
+            "hand" => Icon::Hand,
+            "shield" => Icon::Shield,
+            _ => return None,
+        };
+        Some(icon)
+    }
+}
+
+/// Parses an icon name, ignoring case, as read from a configuration file. Accepts the
+/// names recognized by [Icon::from_name], and fails with [crate::Error::UnknownName] for
+/// any other name. Each name maps to its own variant, but several variants display the
+/// same icon:
+///
+/// - `"warning"` and `"exclamation"` display the exclamation point.
+/// - `"information"`, `"info"` and `"asterisk"` display the letter 'i'.
+/// - `"error"`, `"stop"` and `"hand"` display the letter 'x'.
+///
+/// ```
+/// use win_dialog::Icon;
+/// use windows::Win32::UI::WindowsAndMessaging::{MB_ICONWARNING, MESSAGEBOX_STYLE};
+///
+/// let icon: Icon = "Warning".parse().unwrap();
+/// assert_eq!(MESSAGEBOX_STYLE::from(icon), MB_ICONWARNING);
+/// assert!("siren".parse::<Icon>().is_err());
+/// ```
+impl FromStr for Icon {
+    type Err = crate::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Icon::from_name(name).ok_or_else(|| crate::Error::UnknownName {
+            kind: "icon",
+            name: name.to_string(),
+        })
+    }
+}
+
+/// Whether the deprecated `Icon::Question` variant exists, which is the case when the
+/// `deprecated` feature is enabled. Lets code that is compiled with and without the
+/// feature check for the icon without a `#[cfg]` of its own, for example before offering
+/// it in a settings menu.
+pub const fn question_icon_available() -> bool {
+    cfg!(feature = "deprecated")
+}
+
+/// Plays the system sound associated with an [Icon], without displaying a dialog. This
+/// is the same sound that Windows plays when a message box with that icon is shown. The
+/// sound is played asynchronously, so this function returns immediately.
+#[cfg(all(windows, not(feature = "mock")))]
+pub fn beep(icon: Icon) -> crate::Result<()> {
+    windows_targets::link!("user32.dll" "system" fn MessageBeep(utype: MESSAGEBOX_STYLE) -> BOOL);
+
+    if unsafe { MessageBeep(icon.into()) }.as_bool() {
+        Ok(())
+    } else {
+        Err(crate::Error::SystemError(unsafe { GetLastError() }))
+    }
+}
+
+/// System sounds are only available on Windows. On other platforms this always fails
+/// with [crate::Error::Unsupported].
+#[cfg(all(not(windows), not(feature = "mock")))]
+pub fn beep(_icon: Icon) -> crate::Result<()> {
+    Err(crate::Error::Unsupported)
+}
+
+/// With the `mock` feature enabled, no sound is played.
+#[cfg(feature = "mock")]
+pub fn beep(_icon: Icon) -> crate::Result<()> {
+    Ok(())
 }
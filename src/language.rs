@@ -0,0 +1,82 @@
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::Foundation::GetLastError;
+
+#[cfg(all(windows, not(feature = "mock")))]
+windows_targets::link!("kernel32.dll" "system" fn GetThreadUILanguage() -> u16);
+#[cfg(all(windows, not(feature = "mock")))]
+windows_targets::link!("kernel32.dll" "system" fn SetThreadUILanguage(langid: u16) -> u16);
+
+/// The primary language identifiers of Arabic and Hebrew.
+const RTL_LANGUAGES: [u16; 2] = [0x01, 0x0D];
+
+/// Whether the language identifier denotes Hebrew or Arabic, which are read from right to
+/// left.
+pub(crate) fn is_rtl_language(language: u16) -> bool {
+    // The primary language is stored in the low ten bits of the language identifier.
+    RTL_LANGUAGES.contains(&(language & 0x3FF))
+}
+
+/// Overrides the user interface language of the current thread, in which Windows loads the
+/// text of the standard buttons, until it is dropped. See [crate::WinDialog::with_language].
+#[must_use]
+pub(crate) struct ThreadLanguage {
+    /// The language of the thread before it was overridden, which is restored on drop.
+    #[cfg_attr(any(not(windows), feature = "mock"), allow(dead_code))]
+    previous: u16,
+}
+
+impl ThreadLanguage {
+    /// Switches the current thread to the language, and returns a guard that switches it
+    /// back.
+    pub(crate) fn set(language: u16) -> crate::Result<Self> {
+        let previous = set_thread_language(language)?;
+        Ok(Self { previous })
+    }
+}
+
+impl Drop for ThreadLanguage {
+    fn drop(&mut self) {
+        restore_thread_language(self.previous);
+    }
+}
+
+/// Sets the user interface language of the thread and returns the previous one.
+#[cfg(all(windows, not(feature = "mock")))]
+fn set_thread_language(language: u16) -> crate::Result<u16> {
+    let previous = unsafe { GetThreadUILanguage() };
+    // The function returns the language that was set, which differs from the requested one
+    // if the language is not installed or invalid.
+    match unsafe { SetThreadUILanguage(language) } == language {
+        true => Ok(previous),
+        false => Err(crate::Error::SystemError(unsafe { GetLastError() })),
+    }
+}
+
+/// Switches the thread back to the language that it used before.
+#[cfg(all(windows, not(feature = "mock")))]
+fn restore_thread_language(previous: u16) {
+    // Restoring a language that was in use a moment ago does not fail.
+    let _ = unsafe { SetThreadUILanguage(previous) };
+}
+
+/// Threads have no Windows user interface language on other platforms, so overriding it
+/// always fails with [crate::Error::Unsupported].
+#[cfg(all(not(windows), not(feature = "mock")))]
+fn set_thread_language(_language: u16) -> crate::Result<u16> {
+    Err(crate::Error::Unsupported)
+}
+
+/// Without Windows, the language is never overridden, so there is nothing to restore.
+#[cfg(all(not(windows), not(feature = "mock")))]
+fn restore_thread_language(_previous: u16) {}
+
+/// With the `mock` feature enabled, no dialog is displayed, so the language of the thread
+/// is left alone.
+#[cfg(feature = "mock")]
+fn set_thread_language(language: u16) -> crate::Result<u16> {
+    Ok(language)
+}
+
+/// With the `mock` feature enabled, the language was never overridden.
+#[cfg(feature = "mock")]
+fn restore_thread_language(_previous: u16) {}
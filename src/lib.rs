@@ -20,7 +20,7 @@
 //!
 //! ### Simple Example:
 //!
-//! ```rust
+//! ```rust,no_run
 //! use win_dialog::{style, Icon, WinDialog};
 //! use windows::Win32::Foundation::HWND;
 //!
@@ -34,22 +34,179 @@
 //! println!("{res:?}");
 //! ```
 //!
+//! ## Thread Safety
+//!
+//! [WinDialog], [WinDialogWithParent] and [WinTaskDialog] are `Send` and `Sync`, so a
+//! dialog can be configured on one thread and shown on another, for example with
+//! [WinDialog::show_detached]. The [DialogHandle] returned by it and the
+//! [ProgressController] of a task dialog can be used from any thread as well.
+//!
+//! The parent window of a [WinDialogWithParent] is only a handle, which is why the type
+//! is `Send`. Windows does not prevent showing a message box for a parent window that
+//! belongs to another thread, but doing so ties the input of both threads together, and
+//! the message box stops responding while that thread is blocked. Show such dialogs on
+//! the thread that created the parent window.
+//!
+//! Dialogs shown concurrently from several threads can overlap. Share a [DialogQueue]
+//! between the threads to display them one at a time instead.
+//!
+//! ## Serialization
+//!
+//! With the `serde` feature enabled, the responses, [AnyResponse], [Icon] and [Modality]
+//! can be serialized and deserialized. Each variant is represented by its name:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use std::fmt::Debug;
+//!
+//! use serde::{de::DeserializeOwned, Serialize};
+//! use win_dialog::style::{
+//!     AbortRetryIgnoreResponse, CancelRetryContinueResponse, OkCancelResponse, OkResponse,
+//!     RetryCancelResponse, YesNoCancelResponse, YesNoResponse,
+//! };
+//! use win_dialog::{AnyResponse, Icon, Modality};
+//!
+//! fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T, name: &str) {
+//!     let serialized = serde_json::to_string(&value).unwrap();
+//!     assert_eq!(serialized, format!("\"{name}\""));
+//!     assert_eq!(serde_json::from_str::<T>(&serialized).unwrap(), value);
+//! }
+//!
+//! macro_rules! round_trip {
+//!     ($($kind:ident::$variant:ident),* $(,)?) => {
+//!         $(round_trip($kind::$variant, stringify!($variant));)*
+//!     };
+//! }
+//!
+//! round_trip!(OkResponse::Ok, OkCancelResponse::Ok, OkCancelResponse::Cancel);
+//! round_trip!(
+//!     AbortRetryIgnoreResponse::Abort,
+//!     AbortRetryIgnoreResponse::Retry,
+//!     AbortRetryIgnoreResponse::Ignore,
+//! );
+//! round_trip!(
+//!     YesNoCancelResponse::Yes,
+//!     YesNoCancelResponse::No,
+//!     YesNoCancelResponse::Cancel,
+//! );
+//! round_trip!(YesNoResponse::Yes, YesNoResponse::No);
+//! round_trip!(RetryCancelResponse::Retry, RetryCancelResponse::Cancel);
+//! round_trip!(
+//!     CancelRetryContinueResponse::Cancel,
+//!     CancelRetryContinueResponse::Retry,
+//!     CancelRetryContinueResponse::Continue,
+//! );
+//! round_trip!(
+//!     AnyResponse::Ok,
+//!     AnyResponse::Cancel,
+//!     AnyResponse::Yes,
+//!     AnyResponse::No,
+//!     AnyResponse::Abort,
+//!     AnyResponse::Retry,
+//!     AnyResponse::Ignore,
+//!     AnyResponse::Continue,
+//! );
+//! round_trip!(
+//!     Icon::Exclamation,
+//!     Icon::Warning,
+//!     Icon::Information,
+//!     Icon::Asterisk,
+//!     Icon::Stop,
+//!     Icon::Error,
+//!     Icon::Hand,
+//!     Icon::Shield,
+//! );
+//! #[cfg(feature = "deprecated")]
+//! #[allow(deprecated)]
+//! round_trip!(Icon::Question);
+//! round_trip!(Modality::App, Modality::Task, Modality::System);
+//! # }
+//! ```
+//!
 
+/// Contains the DialogConfig for dialogs defined as data.
+mod config;
+/// Enum modeling the default button options.
+mod default_button;
+/// Options applied to every new dialog of the application.
+mod defaults;
+/// Contains the descriptor of the resolved parameters of a dialog.
+mod describe;
 /// Contains the core WinDialog struct builder.
 mod dialog;
+/// Contains the DynDialog for dialogs whose style is chosen at runtime.
+mod dynamic;
 /// Errors that could occur when rendering the dialog.
 mod error;
-/// Contains enum modeling the available icons.
+/// Contains the handle for waiting on dialogs shown on a separate thread.
+mod handle;
+/// Watches a thread for the window of the dialog it displays.
+mod hook;
+/// Contains enum modeling the available icons and their sounds.
 mod icon;
+/// Overrides the language of the standard buttons while a dialog is displayed.
+mod language;
+/// Test support for injecting dialog responses.
+#[cfg(feature = "mock")]
+mod mock;
 /// Enum modeling the modality options available.
 mod modality;
+/// Reports the outcome of every dialog to a hook registered by the application.
+mod observer;
+/// Helpers for moving dialog windows to a particular place on screen.
+#[cfg(all(windows, not(feature = "mock")))]
+mod position;
+/// Contains the controller for the progress bar of a task dialog.
+mod progress;
+/// Shows dialogs from several threads one at a time.
+mod queue;
+/// Test support for observing the dialogs that are shown.
+#[cfg(feature = "record")]
+mod record;
+/// Plays custom sound files while a dialog is displayed.
+mod sound;
 /// Traits and marker structs modeling the different styles of dialog box.
 pub mod style;
+/// Contains the WinTaskDialog struct builder for dialogs with custom buttons.
+mod task_dialog;
 
-// pub use dialog::AnyResponse;
-pub use dialog::{WinDialog, WinDialogWithParent};
-pub use error::Error;
+pub use config::{DialogConfig, StyleKind};
+pub use default_button::{DefaultButton, DefaultChoice};
+pub use defaults::{set_global_defaults, GlobalDefaults};
+pub use describe::DialogDescriptor;
+pub use dialog::{AnyResponse, Timed, WinDialog, WinDialogWithParent};
+pub use dynamic::{DynDialog, DynStyle};
+pub use error::{Error, RetryError, UserDeclined};
+pub use handle::DialogHandle;
 /// Custom error type alias for the crate.
 pub type Result<T = style::OkCancelResponse> = std::result::Result<T, crate::error::Error>;
-pub use icon::Icon;
+pub use icon::{beep, question_icon_available, Icon};
+#[cfg(feature = "mock")]
+pub use mock::{clear_mock_response, set_mock_response};
 pub use modality::Modality;
+pub use observer::set_result_observer;
+pub use progress::ProgressController;
+pub use queue::DialogQueue;
+#[cfg(feature = "record")]
+pub use record::take_recorded_dialogs;
+pub use style::{
+    AbortRetryIgnore, CancelRetryContinue, DialogStyle, OkCancel, Ok_, RetryCancel, YesNo,
+    YesNoCancel,
+};
+pub use task_dialog::{
+    ButtonKind, CommandLink, CustomButton, RadioButton, TaskDialogResponse, WinTaskDialog,
+};
+
+// The builders, the handle of a detached dialog and the progress controller are sent
+// between threads, so losing Send or Sync would be a breaking change.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<WinDialog<OkCancel>>();
+    assert_send_sync::<WinDialogWithParent<OkCancel>>();
+    assert_send_sync::<WinTaskDialog>();
+    assert_send_sync::<DynDialog>();
+    assert_send_sync::<DialogHandle<OkCancel>>();
+    assert_send_sync::<ProgressController>();
+    assert_send_sync::<DialogQueue>();
+};
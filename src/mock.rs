@@ -0,0 +1,66 @@
+use std::cell::Cell;
+
+use windows::Win32::UI::WindowsAndMessaging::MESSAGEBOX_RESULT;
+
+thread_local! {
+    /// The response code that dialogs shown on this thread will return.
+    static MOCK_RESPONSE: Cell<Option<i32>> = const { Cell::new(None) };
+}
+
+/// Make every dialog shown on the current thread return the given response code instead
+/// of displaying a message box. The code stays in effect until it is replaced or cleared
+/// with [clear_mock_response], and is converted exactly like a real response would be.
+///
+/// Only available with the `mock` feature, which is intended for testing code that
+/// branches on the user's response.
+///
+/// The code only applies to the current thread. Dialogs that this crate shows on another
+/// thread, with [crate::WinDialog::show_detached] or `show_async`, use the code of the
+/// thread that showed them. Threads spawned by the application need their own call.
+///
+/// ```rust
+/// use win_dialog::style::YesNoResponse;
+/// use win_dialog::{set_mock_response, WinDialog, YesNo};
+/// use windows::Win32::UI::WindowsAndMessaging::IDYES;
+///
+/// set_mock_response(IDYES.0);
+/// let response = WinDialog::new("Overwrite the file?")
+///     .with_style(YesNo)
+///     .show()
+///     .unwrap();
+/// assert_eq!(response, YesNoResponse::Yes);
+///
+/// let detached = WinDialog::new("Delete the backup?")
+///     .with_style(YesNo)
+///     .show_detached();
+/// assert_eq!(detached.join().unwrap(), YesNoResponse::Yes);
+/// ```
+pub fn set_mock_response(code: i32) {
+    MOCK_RESPONSE.with(|response| response.set(Some(code)));
+}
+
+/// Remove the response code set with [set_mock_response]. Dialogs shown on the current
+/// thread afterwards fail with [crate::Error::NoMockResponse].
+pub fn clear_mock_response() {
+    MOCK_RESPONSE.with(|response| response.set(None));
+}
+
+/// Returns the response code set for the current thread.
+pub(crate) fn mock_response() -> crate::Result<MESSAGEBOX_RESULT> {
+    MOCK_RESPONSE
+        .with(Cell::get)
+        .map(MESSAGEBOX_RESULT)
+        .ok_or(crate::Error::NoMockResponse)
+}
+
+/// Returns the response code set for the current thread, if any, so that it can be
+/// carried over to the thread a dialog is shown on. See [inherit_mock_response].
+pub(crate) fn current_mock_response() -> Option<i32> {
+    MOCK_RESPONSE.with(Cell::get)
+}
+
+/// Replaces the response code of the current thread with one taken from another thread
+/// with [current_mock_response].
+pub(crate) fn inherit_mock_response(code: Option<i32>) {
+    MOCK_RESPONSE.with(|response| response.set(code));
+}
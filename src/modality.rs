@@ -1,9 +1,12 @@
+use std::str::FromStr;
+
 use windows::Win32::UI::WindowsAndMessaging::{
     MB_APPLMODAL, MB_SYSTEMMODAL, MB_TASKMODAL, MESSAGEBOX_STYLE,
 };
 
 /// Indicate the modality of the dialog box.
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Modality {
     #[default]
     /// The user must respond to the message box before continuing work in the window
@@ -16,21 +19,24 @@ pub enum Modality {
     /// [Modality::App] is the default.
     App,
 
-    /// Produces the same behavior as [Modality::App] except that the message box has the
-    /// WS_EX_TOPMOST style. Use system-modal message boxes to notify the user of serious,
-    /// potentially damaging errors that require immediate attention (for example, running
-    /// out of memory). This flag has no effect on the user's ability to interact with
-    /// windows other than those associated with that set by [crate::WinDialog::set_parent_window].
-    Task,
-
     /// Produces the same behavior as [Modality::App] except that all the top-level windows
     /// belonging to the current thread are disabled if no parent window has been provided
     /// using [crate::WinDialog::set_parent_window]. Use this flag when the calling
     /// application or library does not have a window handle available but still needs to
     /// prevent input to other windows in the calling thread without suspending other threads.
+    /// With a parent window, it behaves exactly like [Modality::App].
+    Task,
+
+    /// Produces the same behavior as [Modality::App] except that the message box has the
+    /// WS_EX_TOPMOST style. Use system-modal message boxes to notify the user of serious,
+    /// potentially damaging errors that require immediate attention (for example, running
+    /// out of memory). This flag has no effect on the user's ability to interact with
+    /// windows other than those associated with that set by [crate::WinDialog::set_parent_window].
     System,
 }
 
+/// The modality flags are mutually exclusive values within [MB_MODEMASK](windows::Win32::UI::WindowsAndMessaging::MB_MODEMASK),
+/// so exactly one of them is ORed into the style of a message box.
 impl From<Modality> for MESSAGEBOX_STYLE {
     fn from(value: Modality) -> Self {
         match value {
@@ -40,3 +46,36 @@ impl From<Modality> for MESSAGEBOX_STYLE {
         }
     }
 }
+
+impl Modality {
+    /// Every modality, in the order in which they are declared, for example to offer them
+    /// in a menu.
+    pub fn all() -> [Modality; 3] {
+        [Modality::App, Modality::Task, Modality::System]
+    }
+}
+
+/// Parses the name of a modality, ignoring case: `"app"`, `"task"` or `"system"`. Fails
+/// with [crate::Error::UnknownName] for any other name.
+///
+/// ```
+/// use win_dialog::Modality;
+///
+/// assert_eq!("Task".parse::<Modality>().unwrap(), Modality::Task);
+/// assert!("window".parse::<Modality>().is_err());
+/// ```
+impl FromStr for Modality {
+    type Err = crate::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "app" => Ok(Modality::App),
+            "task" => Ok(Modality::Task),
+            "system" => Ok(Modality::System),
+            _ => Err(crate::Error::UnknownName {
+                kind: "modality",
+                name: name.to_string(),
+            }),
+        }
+    }
+}
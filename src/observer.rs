@@ -0,0 +1,54 @@
+use std::sync::OnceLock;
+
+use crate::describe::DialogDescriptor;
+
+/// A hook registered with [set_result_observer].
+type ResultObserver = Box<dyn Fn(&DialogDescriptor, i32) + Send + Sync>;
+
+/// The observer registered with [set_result_observer].
+static RESULT_OBSERVER: OnceLock<ResultObserver> = OnceLock::new();
+
+/// Register a hook that is called with the outcome of every message box the application
+/// shows, for example to collect telemetry about dialog usage in one place. It receives a
+/// [DialogDescriptor] of the dialog and the raw result code returned by the MessageBox api,
+/// such as `IDOK`, before the code is converted into the response of the style. It is
+/// independent of the `tracing` feature.
+///
+/// The observer runs synchronously on the thread that showed the dialog, after the user
+/// dismissed it and before `show` returns, so it delays the caller for as long as it runs.
+/// Dialogs that fail to be shown are not reported.
+///
+/// The observer can only be set once, typically at startup. Later calls leave it
+/// unchanged.
+///
+/// ```rust
+/// # #[cfg(feature = "mock")]
+/// # {
+/// use std::sync::Mutex;
+///
+/// use win_dialog::{set_mock_response, set_result_observer, DynDialog, WinDialog};
+/// use windows::Win32::UI::WindowsAndMessaging::IDOK;
+///
+/// static SHOWN: Mutex<Vec<(String, i32)>> = Mutex::new(Vec::new());
+///
+/// set_result_observer(|dialog, code| {
+///     SHOWN.lock().unwrap().push((dialog.content.clone(), code));
+/// });
+///
+/// set_mock_response(IDOK.0);
+/// WinDialog::new("Saved").show().unwrap();
+/// DynDialog::from(WinDialog::new("Exported")).show().unwrap();
+/// assert_eq!(
+///     *SHOWN.lock().unwrap(),
+///     [("Saved".to_string(), IDOK.0), ("Exported".to_string(), IDOK.0)]
+/// );
+/// # }
+/// ```
+pub fn set_result_observer(observer: impl Fn(&DialogDescriptor, i32) + Send + Sync + 'static) {
+    let _ = RESULT_OBSERVER.set(Box::new(observer));
+}
+
+/// Returns the registered observer, if any.
+pub(crate) fn result_observer() -> Option<&'static ResultObserver> {
+    RESULT_OBSERVER.get()
+}
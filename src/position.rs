@@ -0,0 +1,156 @@
+use windows::Win32::Foundation::{BOOL, HWND, LPARAM, POINT, RECT};
+use windows::Win32::Graphics::Gdi::{
+    EnumDisplayMonitors, GetMonitorInfoW, MapWindowPoints, MonitorFromPoint, HDC, HMONITOR,
+    MONITORINFO, MONITOR_DEFAULTTOPRIMARY,
+};
+use windows::Win32::UI::WindowsAndMessaging::{
+    EnumChildWindows, GetClassNameW, GetClientRect, GetParent, GetWindowLongW, GetWindowRect,
+    SetWindowPos, BS_DEFPUSHBUTTON, BS_PUSHBUTTON, BS_TYPEMASK, GWL_EXSTYLE, GWL_STYLE,
+    HWND_DESKTOP, HWND_TOP, HWND_TOPMOST, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER,
+    WS_EX_LAYOUTRTL,
+};
+
+/// Lists the display monitors in the order in which Windows enumerates them.
+fn monitors() -> Vec<HMONITOR> {
+    /// Appends each enumerated monitor to the list that `data` points to.
+    unsafe extern "system" fn collect(
+        monitor: HMONITOR,
+        _context: HDC,
+        _area: *mut RECT,
+        data: LPARAM,
+    ) -> BOOL {
+        let monitors = &mut *(data.0 as *mut Vec<HMONITOR>);
+        monitors.push(monitor);
+        true.into()
+    }
+
+    let mut monitors = Vec::new();
+    let data = LPARAM(&mut monitors as *mut Vec<HMONITOR> as isize);
+    // If enumeration fails, the missing monitors fall back to the primary one.
+    let _ = unsafe { EnumDisplayMonitors(None, None, Some(collect), data) };
+    monitors
+}
+
+/// Moves a window so that it is centered in the given area of the screen, keeping its size.
+/// Positioning is cosmetic, so failures are ignored and the window stays where it is.
+pub(crate) fn center_in(window: HWND, area: RECT) {
+    let mut rect = RECT::default();
+    if unsafe { GetWindowRect(window, &mut rect) }.is_err() {
+        return;
+    }
+
+    let width = rect.right - rect.left;
+    let height = rect.bottom - rect.top;
+    let x = area.left + (area.right - area.left - width) / 2;
+    let y = area.top + (area.bottom - area.top - height) / 2;
+
+    let flags = SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE;
+    let _ = unsafe { SetWindowPos(window, HWND_TOP, x, y, 0, 0, flags) };
+}
+
+/// Moves a window to the center of the work area of the monitor at the given index, counted
+/// in the order in which Windows enumerates monitors. Indices without a monitor fall back to
+/// the primary monitor.
+pub(crate) fn center_on_monitor(window: HWND, index: usize) {
+    let monitor = monitors()
+        .get(index)
+        .copied()
+        .unwrap_or_else(primary_monitor);
+    center_in_work_area(window, monitor);
+}
+
+/// Moves a window to the center of the work area of the primary monitor.
+pub(crate) fn center_on_primary_monitor(window: HWND) {
+    center_in_work_area(window, primary_monitor());
+}
+
+/// The monitor that Windows considers the primary one, whose top left corner is the origin
+/// of the screen coordinates.
+fn primary_monitor() -> HMONITOR {
+    unsafe { MonitorFromPoint(POINT::default(), MONITOR_DEFAULTTOPRIMARY) }
+}
+
+/// Moves a window to the center of the work area of the monitor, which excludes the taskbar
+/// and docked toolbars.
+fn center_in_work_area(window: HWND, monitor: HMONITOR) {
+    let mut info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    if unsafe { GetMonitorInfoW(monitor, &mut info) }.as_bool() {
+        center_in(window, info.rcWork);
+    }
+}
+
+/// Places a window above all windows that are not topmost themselves, without moving,
+/// resizing or activating it. Failures are ignored like in [center_in].
+pub(crate) fn make_topmost(window: HWND) {
+    let flags = SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE;
+    let _ = unsafe { SetWindowPos(window, HWND_TOPMOST, 0, 0, 0, 0, flags) };
+}
+
+/// Lists the push buttons among the descendants of a window, in the order in which Windows
+/// enumerates them. Radio buttons, checkboxes and command links are left out.
+fn push_buttons(window: HWND) -> Vec<HWND> {
+    /// Appends each enumerated push button to the list that `data` points to.
+    unsafe extern "system" fn collect(child: HWND, data: LPARAM) -> BOOL {
+        let mut class = [0u16; 16];
+        let length = usize::try_from(GetClassNameW(child, &mut class)).unwrap_or(0);
+        let kind = GetWindowLongW(child, GWL_STYLE) & BS_TYPEMASK;
+        if String::from_utf16_lossy(&class[..length]) == "Button"
+            && (kind == BS_PUSHBUTTON || kind == BS_DEFPUSHBUTTON)
+        {
+            let buttons = &mut *(data.0 as *mut Vec<HWND>);
+            buttons.push(child);
+        }
+        true.into()
+    }
+
+    let mut buttons = Vec::new();
+    let data = LPARAM(&mut buttons as *mut Vec<HWND> as isize);
+    let _ = unsafe { EnumChildWindows(window, Some(collect), data) };
+    buttons
+}
+
+/// Moves the push buttons of a dialog whose layout is mirrored for a right-to-left
+/// language back to its right edge, by mirroring the position of each button within the
+/// area that holds the buttons. Dialogs with a left-to-right layout already display their
+/// buttons on the right, so they are left alone. Failures are ignored like in [center_in].
+pub(crate) fn align_buttons_right(window: HWND) {
+    let ex_style = unsafe { GetWindowLongW(window, GWL_EXSTYLE) } as u32;
+    if ex_style & WS_EX_LAYOUTRTL.0 == 0 {
+        return;
+    }
+
+    for button in push_buttons(window) {
+        // Task dialogs host each button in a window of its own, which is the one that is
+        // laid out within the content area.
+        let host = unsafe { GetParent(button) };
+        let area = unsafe { GetParent(host) };
+        let mut client = RECT::default();
+        let mut rect = RECT::default();
+        if unsafe { GetClientRect(area, &mut client) }.is_err()
+            || unsafe { GetWindowRect(host, &mut rect) }.is_err()
+        {
+            continue;
+        }
+
+        // Mapping both corners at once keeps left below right in a mirrored window.
+        let mut corners = [
+            POINT {
+                x: rect.left,
+                y: rect.top,
+            },
+            POINT {
+                x: rect.right,
+                y: rect.bottom,
+            },
+        ];
+        unsafe { MapWindowPoints(HWND_DESKTOP, area, &mut corners) };
+        let [top_left, bottom_right] = corners;
+
+        let x = client.right - bottom_right.x;
+        let flags = SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE;
+        let _ = unsafe { SetWindowPos(host, HWND_TOP, x, top_left.y, 0, 0, flags) };
+    }
+}
@@ -0,0 +1,160 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use windows::Win32::Foundation::HWND;
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::Foundation::{LPARAM, WPARAM};
+use windows::Win32::UI::Controls::{
+    TASKDIALOG_MESSAGES, TDM_CLICK_BUTTON, TDM_SET_PROGRESS_BAR_MARQUEE, TDM_SET_PROGRESS_BAR_POS,
+};
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::UI::WindowsAndMessaging::PostMessageW;
+use windows::Win32::UI::WindowsAndMessaging::IDCANCEL;
+
+/// The state shared between a [ProgressController] and the dialog that it controls.
+#[derive(Debug, Default)]
+struct Progress {
+    /// The window of the dialog while it is displayed.
+    window: Option<HWND>,
+    /// The last position set, in percent.
+    position: u8,
+    /// Whether [ProgressController::finish] was called.
+    finished: bool,
+    /// Whether [ProgressController::stop] was called.
+    stopped: bool,
+}
+
+/// Controls the progress bar of a [crate::WinTaskDialog] from any thread while the dialog
+/// is displayed. Pass a clone of the controller to [crate::WinTaskDialog::with_progress_bar]
+/// and keep the original to report progress. Updates made before the dialog appears are
+/// applied as soon as it does.
+///
+/// ```no_run
+/// use win_dialog::{ProgressController, WinTaskDialog};
+///
+/// let progress = ProgressController::new();
+/// let dialog = WinTaskDialog::new("Installing...").with_progress_bar(progress.clone());
+///
+/// std::thread::spawn(move || {
+///     for percent in 0..=100 {
+///         progress.set_progress(percent);
+///         std::thread::sleep(std::time::Duration::from_millis(50));
+///     }
+///     progress.finish();
+/// });
+///
+/// if dialog.show().unwrap().was_cancelled() {
+///     // Roll back the installation.
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ProgressController {
+    /// The state shared by all clones of the controller.
+    shared: Arc<Mutex<Progress>>,
+}
+
+impl PartialEq for ProgressController {
+    /// Controllers are equal if they are clones of each other.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.shared, &other.shared)
+    }
+}
+
+impl ProgressController {
+    /// Create a controller that is not yet attached to a dialog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Locks the shared state. The state is a set of plain values, so it is still
+    /// consistent if a thread panicked while holding the lock.
+    fn lock(&self) -> MutexGuard<'_, Progress> {
+        self.shared
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Move the progress bar to the given percentage. Values above 100 are treated as 100.
+    /// Has no effect on a marquee progress bar.
+    pub fn set_progress(&self, percent: u8) {
+        let mut progress = self.lock();
+        progress.position = percent.min(100);
+        if let Some(window) = progress.window {
+            post(
+                window,
+                TDM_SET_PROGRESS_BAR_POS,
+                usize::from(progress.position),
+            );
+        }
+    }
+
+    /// Halt the animation of a marquee progress bar, displayed with
+    /// [crate::WinTaskDialog::with_marquee]. Has no effect on a regular progress bar.
+    pub fn stop(&self) {
+        let mut progress = self.lock();
+        progress.stopped = true;
+        if let Some(window) = progress.window {
+            post(window, TDM_SET_PROGRESS_BAR_MARQUEE, 0);
+        }
+    }
+
+    /// Close the dialog because the operation has completed. The dialog then reports
+    /// [IDOK](windows::Win32::UI::WindowsAndMessaging::IDOK) rather than the
+    /// [IDCANCEL] that it reports when the user cancels. If the dialog has not appeared yet,
+    /// it closes as soon as it does.
+    pub fn finish(&self) {
+        let mut progress = self.lock();
+        progress.finished = true;
+        if let Some(window) = progress.window {
+            post(window, TDM_CLICK_BUTTON, IDCANCEL.0 as usize);
+        }
+    }
+}
+
+/// The dialog side of the controller, driven by the callback of the task dialog.
+#[cfg_attr(any(not(windows), feature = "mock"), allow(dead_code))]
+impl ProgressController {
+    /// Whether [ProgressController::finish] was called.
+    pub(crate) fn is_finished(&self) -> bool {
+        self.lock().finished
+    }
+
+    /// Connects the controller to the window of the dialog once it has been created, and
+    /// applies the updates that were made before. A marquee progress bar is started unless
+    /// it was already stopped.
+    pub(crate) fn attach(&self, window: HWND, marquee: bool) {
+        let mut progress = self.lock();
+        progress.window = Some(window);
+        match marquee {
+            true => post(
+                window,
+                TDM_SET_PROGRESS_BAR_MARQUEE,
+                usize::from(!progress.stopped),
+            ),
+            false => post(
+                window,
+                TDM_SET_PROGRESS_BAR_POS,
+                usize::from(progress.position),
+            ),
+        }
+        if progress.finished {
+            post(window, TDM_CLICK_BUTTON, IDCANCEL.0 as usize);
+        }
+    }
+
+    /// Disconnects the controller from the window of the dialog when it is destroyed.
+    pub(crate) fn detach(&self) {
+        self.lock().window = None;
+    }
+}
+
+/// Posts a task dialog message to the window of the dialog. Posting, rather than sending,
+/// never blocks the calling thread on the thread that displays the dialog.
+#[cfg(all(windows, not(feature = "mock")))]
+fn post(window: HWND, message: TASKDIALOG_MESSAGES, wparam: usize) {
+    // Fails only if the window was destroyed in the meantime, which is harmless.
+    let _ = unsafe { PostMessageW(window, message.0 as u32, WPARAM(wparam), LPARAM(0)) };
+}
+
+/// Without Windows, or with the `mock` feature enabled, no dialog window is ever attached.
+#[cfg(any(not(windows), feature = "mock"))]
+fn post(_window: HWND, _message: TASKDIALOG_MESSAGES, _wparam: usize) {}
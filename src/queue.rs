@@ -0,0 +1,87 @@
+use std::sync::Mutex;
+
+use crate::style::DialogStyle;
+use crate::{WinDialog, WinDialogWithParent};
+
+/// Serializes dialogs shown from several threads, so that only one of them is visible at
+/// a time. A dialog shown through the queue while another one is open waits for the user
+/// to respond to the open one before it appears. Dialogs are shown in no particular order
+/// once the queue frees up.
+///
+/// The queue is usually shared between threads by reference, for example from a
+/// `static`, or by wrapping it in an [Arc](std::sync::Arc).
+///
+/// ```rust
+/// # #[cfg(feature = "mock")]
+/// # {
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::time::Duration;
+///
+/// use win_dialog::{set_mock_response, set_result_observer, DialogQueue, WinDialog};
+/// use windows::Win32::UI::WindowsAndMessaging::IDOK;
+///
+/// static QUEUE: DialogQueue = DialogQueue::new();
+/// static OPEN: AtomicUsize = AtomicUsize::new(0);
+/// static MOST_OPEN: AtomicUsize = AtomicUsize::new(0);
+///
+/// // The observer runs before the dialog gives up its turn, so it counts open dialogs.
+/// set_result_observer(|_, _| {
+///     MOST_OPEN.fetch_max(OPEN.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+///     std::thread::sleep(Duration::from_millis(50));
+///     OPEN.fetch_sub(1, Ordering::SeqCst);
+/// });
+///
+/// let subsystems = ["Network", "Storage", "Display"].map(|name| {
+///     std::thread::spawn(move || {
+///         set_mock_response(IDOK.0);
+///         QUEUE.show(WinDialog::new(format!("{name} is unavailable")))
+///     })
+/// });
+/// for subsystem in subsystems {
+///     assert!(subsystem.join().unwrap().is_ok());
+/// }
+/// assert_eq!(MOST_OPEN.load(Ordering::SeqCst), 1);
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct DialogQueue {
+    /// Held for as long as a dialog of the queue is displayed.
+    turn: Mutex<()>,
+}
+
+impl DialogQueue {
+    /// Creates an empty queue.
+    pub const fn new() -> Self {
+        Self {
+            turn: Mutex::new(()),
+        }
+    }
+
+    /// Waits until no other dialog of the queue is displayed, then displays the dialog
+    /// and returns the response.
+    pub fn show<T>(&self, dialog: WinDialog<T>) -> crate::Result<T::Return>
+    where
+        T: DialogStyle,
+    {
+        self.in_turn(|| dialog.show())
+    }
+
+    /// Waits until no other dialog of the queue is displayed, then displays the dialog
+    /// and returns the response. See [DialogQueue::show] for more information.
+    pub fn show_with_parent<T>(&self, dialog: WinDialogWithParent<T>) -> crate::Result<T::Return>
+    where
+        T: DialogStyle,
+    {
+        self.in_turn(|| dialog.show())
+    }
+
+    /// Runs the closure while holding the queue's turn.
+    fn in_turn<R>(&self, show: impl FnOnce() -> R) -> R {
+        // Nothing is guarded by the lock, so a dialog that panicked leaves no broken state.
+        let _turn = self
+            .turn
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        show()
+    }
+}
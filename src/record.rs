@@ -0,0 +1,41 @@
+use std::cell::RefCell;
+
+use crate::describe::DialogDescriptor;
+
+thread_local! {
+    /// The dialogs shown on this thread since the recording was last taken.
+    static RECORDED: RefCell<Vec<DialogDescriptor>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Returns a [DialogDescriptor] for every message box shown on the current thread since the
+/// previous call, in the order in which they were shown, and starts a new recording.
+/// Lets integration tests assert which dialogs an application would have displayed.
+///
+/// Only available with the `record` feature. It enables the `mock` feature as well, so
+/// that no message box is displayed: the response comes from [crate::set_mock_response],
+/// and the dialog is recorded even if showing it fails because no response was set.
+///
+/// ```rust
+/// use win_dialog::{set_mock_response, take_recorded_dialogs, Icon, WinDialog};
+/// use windows::Win32::UI::WindowsAndMessaging::IDOK;
+///
+/// set_mock_response(IDOK.0);
+/// WinDialog::new("The disk is almost full")
+///     .with_icon(Icon::Warning)
+///     .show()
+///     .unwrap();
+///
+/// let recorded = take_recorded_dialogs();
+/// assert_eq!(recorded.len(), 1);
+/// assert_eq!(recorded[0].content, "The disk is almost full");
+/// assert_eq!(recorded[0].icon, Some(Icon::Warning));
+/// assert!(take_recorded_dialogs().is_empty());
+/// ```
+pub fn take_recorded_dialogs() -> Vec<DialogDescriptor> {
+    RECORDED.with(|recorded| recorded.take())
+}
+
+/// Adds a dialog that is about to be shown to the recording of the current thread.
+pub(crate) fn record(descriptor: DialogDescriptor) {
+    RECORDED.with(|recorded| recorded.borrow_mut().push(descriptor));
+}
@@ -0,0 +1,103 @@
+use std::path::PathBuf;
+
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::core::PCWSTR;
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::Foundation::{BOOL, HMODULE};
+
+#[cfg(all(windows, not(feature = "mock")))]
+windows_targets::link!("winmm.dll" "system" fn PlaySoundW(pszsound: PCWSTR, hmod: HMODULE, fdwsound: u32) -> BOOL);
+
+/// Play the sound asynchronously, so that the call returns immediately.
+#[cfg(all(windows, not(feature = "mock")))]
+const SND_ASYNC: u32 = 0x0001;
+/// Do not fall back to the default system sound if the file cannot be played.
+#[cfg(all(windows, not(feature = "mock")))]
+const SND_NODEFAULT: u32 = 0x0002;
+/// Repeat the sound until it is stopped.
+#[cfg(all(windows, not(feature = "mock")))]
+const SND_LOOP: u32 = 0x0008;
+/// The sound is the path of a file.
+#[cfg(all(windows, not(feature = "mock")))]
+const SND_FILENAME: u32 = 0x0002_0000;
+
+/// A sound file that is played when a dialog appears. See [crate::WinDialog::with_sound].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Sound {
+    /// The path of the `.wav` file.
+    path: PathBuf,
+    /// Whether the sound repeats until the dialog closes.
+    looping: bool,
+}
+
+impl Sound {
+    /// Creates a sound that plays the file once, or repeatedly if `looping` is set.
+    pub(crate) fn new(path: PathBuf, looping: bool) -> Self {
+        Self { path, looping }
+    }
+
+    /// Starts playing the sound, which stops when the returned guard is dropped. Fails with
+    /// [crate::Error::InvalidSound] if the file does not exist or cannot be played.
+    pub(crate) fn play(&self) -> crate::Result<PlayingSound> {
+        if !self.path.is_file() {
+            return Err(crate::Error::InvalidSound(self.path.clone()));
+        }
+
+        play_sound(self)?;
+        Ok(PlayingSound)
+    }
+}
+
+/// Stops the sound that is playing when dropped.
+pub(crate) struct PlayingSound;
+
+impl Drop for PlayingSound {
+    fn drop(&mut self) {
+        stop_sound();
+    }
+}
+
+/// Starts playing the sound file with the PlaySound api.
+#[cfg(all(windows, not(feature = "mock")))]
+fn play_sound(sound: &Sound) -> crate::Result<()> {
+    let path = crate::dialog::to_wide(&sound.path.to_string_lossy())?;
+    let flags = match sound.looping {
+        true => SND_ASYNC | SND_NODEFAULT | SND_FILENAME | SND_LOOP,
+        false => SND_ASYNC | SND_NODEFAULT | SND_FILENAME,
+    };
+
+    match unsafe { PlaySoundW(PCWSTR::from_raw(path.as_ptr()), HMODULE::default(), flags) }
+        .as_bool()
+    {
+        true => Ok(()),
+        false => Err(crate::Error::InvalidSound(sound.path.clone())),
+    }
+}
+
+/// Stops any sound started with [play_sound].
+#[cfg(all(windows, not(feature = "mock")))]
+fn stop_sound() {
+    // Stopping fails only if nothing is playing, which is fine.
+    let _ = unsafe { PlaySoundW(PCWSTR::null(), HMODULE::default(), 0) };
+}
+
+/// Sounds can only be played on Windows. On other platforms playing always fails with
+/// [crate::Error::Unsupported].
+#[cfg(all(not(windows), not(feature = "mock")))]
+fn play_sound(_sound: &Sound) -> crate::Result<()> {
+    Err(crate::Error::Unsupported)
+}
+
+/// Without Windows, no sound is ever played, so there is nothing to stop.
+#[cfg(all(not(windows), not(feature = "mock")))]
+fn stop_sound() {}
+
+/// With the `mock` feature enabled, no sound is played.
+#[cfg(feature = "mock")]
+fn play_sound(_sound: &Sound) -> crate::Result<()> {
+    Ok(())
+}
+
+/// With the `mock` feature enabled, no sound is played, so there is nothing to stop.
+#[cfg(feature = "mock")]
+fn stop_sound() {}
@@ -1,16 +1,106 @@
+use crate::{DefaultButton, UserDeclined};
 use windows::Win32::UI::WindowsAndMessaging::{
-    IDABORT, IDCANCEL, IDCONTINUE, IDIGNORE, IDNO, IDOK, IDRETRY, IDYES, MB_ABORTRETRYIGNORE,
-    MB_CANCELTRYCONTINUE, MB_OK, MB_OKCANCEL, MB_RETRYCANCEL, MB_YESNO, MB_YESNOCANCEL,
-    MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
+    IDABORT, IDCANCEL, IDCLOSE, IDCONTINUE, IDHELP, IDIGNORE, IDNO, IDOK, IDRETRY, IDTIMEOUT,
+    IDTRYAGAIN, IDYES, MB_ABORTRETRYIGNORE, MB_CANCELTRYCONTINUE, MB_OK, MB_OKCANCEL,
+    MB_RETRYCANCEL, MB_YESNO, MB_YESNOCANCEL, MESSAGEBOX_RESULT, MESSAGEBOX_STYLE,
 };
 
 /// Trait indicating the type of response style of dialog returns,
 /// how to convert the raw response to the concrete return type, and
 /// how to convert the type into the style code Windows understands.
-pub trait DialogStyle: Sized + Default + Into<MESSAGEBOX_STYLE> {
-    /// The concrete type that this style returns
+pub trait DialogStyle: Sized + Default + Copy + Into<MESSAGEBOX_STYLE> {
+    /// The concrete type that this style returns. Every response converts into the code
+    /// that Windows reports for it, and converting the code again gives the original
+    /// response:
+    ///
+    /// ```rust
+    /// use win_dialog::style::{
+    ///     AbortRetryIgnoreResponse, CancelRetryContinueResponse, OkCancelResponse, OkResponse,
+    ///     RetryCancelResponse, YesNoCancelResponse, YesNoResponse,
+    /// };
+    /// use win_dialog::AnyResponse;
+    /// use windows::Win32::UI::WindowsAndMessaging::{
+    ///     IDABORT, IDCANCEL, IDCONTINUE, IDIGNORE, IDNO, IDOK, IDRETRY, IDTRYAGAIN, IDYES,
+    ///     MESSAGEBOX_RESULT,
+    /// };
+    ///
+    /// macro_rules! assert_round_trip {
+    ///     ($($response:ident::$variant:ident => $code:ident),* $(,)?) => {$(
+    ///         let code = MESSAGEBOX_RESULT::from($response::$variant);
+    ///         assert_eq!(code, $code, stringify!($response::$variant));
+    ///         assert_eq!($response::try_from(code).unwrap(), $response::$variant);
+    ///     )*};
+    /// }
+    ///
+    /// assert_round_trip! {
+    ///     OkResponse::Ok => IDOK,
+    ///     OkCancelResponse::Ok => IDOK,
+    ///     OkCancelResponse::Cancel => IDCANCEL,
+    ///     AbortRetryIgnoreResponse::Abort => IDABORT,
+    ///     AbortRetryIgnoreResponse::Retry => IDRETRY,
+    ///     AbortRetryIgnoreResponse::Ignore => IDIGNORE,
+    ///     YesNoResponse::Yes => IDYES,
+    ///     YesNoResponse::No => IDNO,
+    ///     YesNoCancelResponse::Yes => IDYES,
+    ///     YesNoCancelResponse::No => IDNO,
+    ///     YesNoCancelResponse::Cancel => IDCANCEL,
+    ///     RetryCancelResponse::Retry => IDRETRY,
+    ///     RetryCancelResponse::Cancel => IDCANCEL,
+    ///     CancelRetryContinueResponse::Cancel => IDCANCEL,
+    ///     CancelRetryContinueResponse::Retry => IDTRYAGAIN,
+    ///     CancelRetryContinueResponse::Continue => IDCONTINUE,
+    ///     AnyResponse::Ok => IDOK,
+    ///     AnyResponse::Cancel => IDCANCEL,
+    ///     AnyResponse::Yes => IDYES,
+    ///     AnyResponse::No => IDNO,
+    ///     AnyResponse::Abort => IDABORT,
+    ///     AnyResponse::Retry => IDRETRY,
+    ///     AnyResponse::Ignore => IDIGNORE,
+    ///     AnyResponse::Continue => IDCONTINUE,
+    /// }
+    ///
+    /// // Both codes of the 'try again' button are accepted, but only one is produced.
+    /// assert_eq!(
+    ///     CancelRetryContinueResponse::try_from(IDRETRY).unwrap(),
+    ///     CancelRetryContinueResponse::Retry
+    /// );
+    /// assert_eq!(AnyResponse::try_from(IDTRYAGAIN).unwrap(), AnyResponse::Retry);
+    /// ```
     type Return: TryFrom<MESSAGEBOX_RESULT, Error = crate::Error>;
 
+    /// The name of the style, such as `"YesNoCancel"`, for use in diagnostics.
+    const NAME: &'static str;
+
+    /// The number of buttons this style displays, not counting the help button.
+    const BUTTON_COUNT: usize;
+
+    /// The labels of the buttons this style displays, in order, not counting the help
+    /// button. These are the English labels; Windows translates them to the language of
+    /// the system.
+    const BUTTON_LABELS: &'static [&'static str];
+
+    /// The response codes of the buttons this style displays, in the same order as
+    /// [DialogStyle::BUTTON_LABELS].
+    const BUTTON_IDS: &'static [MESSAGEBOX_RESULT];
+
+    /// The position of the button that produces the given response.
+    fn button_for(response: Self::Return) -> DefaultButton;
+
+    /// The default button flag, such as `MB_DEFBUTTON2`, that makes the button producing
+    /// the given response the default. This is the raw form of [DialogStyle::button_for],
+    /// to choose the default button of a dialog use [crate::WinDialog::set_default].
+    ///
+    /// ```rust
+    /// use win_dialog::style::{DialogStyle, YesNoCancel, YesNoCancelResponse};
+    /// use windows::Win32::UI::WindowsAndMessaging::MB_DEFBUTTON3;
+    ///
+    /// let flag = YesNoCancel::default_button_for(YesNoCancelResponse::Cancel);
+    /// assert_eq!(flag, MB_DEFBUTTON3);
+    /// ```
+    fn default_button_for(response: Self::Return) -> MESSAGEBOX_STYLE {
+        Self::button_for(response).into()
+    }
+
     /// A helper method to convert to the raw style code. Under the hood,
     /// simply calls [Into]
     fn style_code(self) -> MESSAGEBOX_STYLE {
@@ -18,6 +108,122 @@ pub trait DialogStyle: Sized + Default + Into<MESSAGEBOX_STYLE> {
     }
 }
 
+/// Describes one of the styles of this module, for tooling that lets the user pick a style
+/// at runtime. See [style_info].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StyleInfo {
+    /// The name of the style, see [DialogStyle::NAME].
+    pub name: &'static str,
+    /// The labels of the buttons of the style, see [DialogStyle::BUTTON_LABELS].
+    pub buttons: &'static [&'static str],
+}
+
+impl StyleInfo {
+    /// Describes the given style.
+    const fn of<T: DialogStyle>() -> Self {
+        Self {
+            name: T::NAME,
+            buttons: T::BUTTON_LABELS,
+        }
+    }
+}
+
+/// Every style of this module, in the order in which they are declared.
+static STYLES: [StyleInfo; 7] = [
+    StyleInfo::of::<Ok_>(),
+    StyleInfo::of::<OkCancel>(),
+    StyleInfo::of::<AbortRetryIgnore>(),
+    StyleInfo::of::<YesNoCancel>(),
+    StyleInfo::of::<YesNo>(),
+    StyleInfo::of::<RetryCancel>(),
+    StyleInfo::of::<CancelRetryContinue>(),
+];
+
+/// Lists the styles of this module with their names and button labels. The number of
+/// buttons of a style is the length of [StyleInfo::buttons], which does not count the help
+/// button.
+///
+/// ```rust
+/// use win_dialog::style::style_info;
+///
+/// let yes_no = style_info()
+///     .iter()
+///     .find(|style| style.name == "YesNo")
+///     .unwrap();
+/// assert_eq!(yes_no.buttons, ["Yes", "No"]);
+/// ```
+pub fn style_info() -> &'static [StyleInfo] {
+    &STYLES
+}
+
+/// Maps a response code that a style does not recognize to the appropriate error.
+/// [IDTIMEOUT] is shared by all styles and indicates that the dialog closed itself
+/// because its duration elapsed.
+pub(crate) fn unrecognized(value: MESSAGEBOX_RESULT) -> crate::Error {
+    if value == IDTIMEOUT {
+        crate::Error::Timeout
+    } else {
+        crate::Error::UnknownResponseCode(value.0)
+    }
+}
+
+/// The response codes that the MessageBox api is documented to return, apart from
+/// [IDTIMEOUT].
+const KNOWN_CODES: [MESSAGEBOX_RESULT; 11] = [
+    IDOK, IDCANCEL, IDABORT, IDRETRY, IDIGNORE, IDYES, IDNO, IDCLOSE, IDHELP, IDTRYAGAIN,
+    IDCONTINUE,
+];
+
+/// Maps a response code that the style `T` does not produce to the appropriate error,
+/// telling codes that Windows returns for other styles apart from unknown ones.
+fn unexpected<T: DialogStyle>(value: MESSAGEBOX_RESULT) -> crate::Error {
+    match KNOWN_CODES.contains(&value) {
+        true => crate::Error::UnexpectedResponseForStyle {
+            code: value.0,
+            style: T::NAME,
+        },
+        false => unrecognized(value),
+    }
+}
+
+/// Implements [Display](std::fmt::Display) and the comparisons with strings for responses,
+/// in terms of their `label` method.
+macro_rules! label_impls {
+    ($($response:ident),* $(,)?) => {$(
+        impl std::fmt::Display for $response {
+            #[doc = concat!("Writes the label of the button, see [", stringify!($response), "::label].")]
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.label())
+            }
+        }
+
+        #[doc = concat!("Compares the response with the label of its button, see [", stringify!($response), "::label].")]
+        impl PartialEq<str> for $response {
+            fn eq(&self, other: &str) -> bool {
+                self.label() == other
+            }
+        }
+
+        impl PartialEq<&str> for $response {
+            fn eq(&self, other: &&str) -> bool {
+                self.label() == *other
+            }
+        }
+    )*};
+}
+pub(crate) use label_impls;
+
+label_impls!(
+    OkResponse,
+    OkCancelResponse,
+    AbortRetryIgnoreResponse,
+    YesNoCancelResponse,
+    YesNoResponse,
+    RetryCancelResponse,
+    CancelRetryContinueResponse,
+);
+
 /// Represents a dialog with just an ok button and a close button. A peculiarity about
 /// this type is that clicking the X button and the OK button return the same response code,
 /// so only use this dialog for informative purposes, but never to allow the user the chance to
@@ -27,6 +233,20 @@ pub struct Ok_;
 
 impl DialogStyle for Ok_ {
     type Return = OkResponse;
+
+    const NAME: &'static str = "Ok_";
+
+    const BUTTON_COUNT: usize = 1;
+
+    const BUTTON_LABELS: &'static [&'static str] = &["OK"];
+
+    const BUTTON_IDS: &'static [MESSAGEBOX_RESULT] = &[IDOK];
+
+    fn button_for(response: Self::Return) -> DefaultButton {
+        match response {
+            OkResponse::Ok => DefaultButton::First,
+        }
+    }
 }
 
 impl From<Ok_> for MESSAGEBOX_STYLE {
@@ -36,12 +256,50 @@ impl From<Ok_> for MESSAGEBOX_STYLE {
 }
 
 /// The possible return values for the [Ok_] dialog.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OkResponse {
     /// The user acknowledged the response.
     Ok,
 }
 
+impl OkResponse {
+    /// Returns true if the response is [OkResponse::Ok].
+    pub fn is_ok(&self) -> bool {
+        matches!(self, OkResponse::Ok)
+    }
+
+    /// Always true, since acknowledging the message is the only possible response.
+    pub fn is_affirmative(&self) -> bool {
+        matches!(self, OkResponse::Ok)
+    }
+
+    /// The label of the button that produces this response, in lowercase, such as `"ok"`.
+    /// The English label is returned regardless of the language of the system. Used by
+    /// the [Display](std::fmt::Display) implementation and by comparisons with strings.
+    pub fn label(&self) -> &'static str {
+        match self {
+            OkResponse::Ok => "ok",
+        }
+    }
+
+    /// The exit code of a command line tool that reports this response, following the
+    /// convention of [crate::AnyResponse::exit_code].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            OkResponse::Ok => 0,
+        }
+    }
+
+    /// The response code that Windows reports for this response, such as `IDOK`. The
+    /// inverse of the [TryFrom] conversion from [MESSAGEBOX_RESULT].
+    pub fn as_code(&self) -> i32 {
+        match self {
+            OkResponse::Ok => IDOK.0,
+        }
+    }
+}
+
 impl TryFrom<MESSAGEBOX_RESULT> for OkResponse {
     type Error = crate::Error;
 
@@ -49,11 +307,19 @@ impl TryFrom<MESSAGEBOX_RESULT> for OkResponse {
         if value == IDOK {
             Ok(OkResponse::Ok)
         } else {
-            Err(crate::Error::UnknownResponseCode(value.0))
+            Err(unexpected::<Ok_>(value))
         }
     }
 }
 
+/// Converts the response back to the code that Windows reports for it, see
+/// [OkResponse::as_code].
+impl From<OkResponse> for MESSAGEBOX_RESULT {
+    fn from(value: OkResponse) -> Self {
+        MESSAGEBOX_RESULT(value.as_code())
+    }
+}
+
 /// Represents a dialog that allows the user to accept a proposed action or reject it.
 /// It features an X button in the top right corner. This button returns the same value
 /// as clicking 'cancel'.
@@ -62,6 +328,21 @@ pub struct OkCancel;
 
 impl DialogStyle for OkCancel {
     type Return = OkCancelResponse;
+
+    const NAME: &'static str = "OkCancel";
+
+    const BUTTON_COUNT: usize = 2;
+
+    const BUTTON_LABELS: &'static [&'static str] = &["OK", "Cancel"];
+
+    const BUTTON_IDS: &'static [MESSAGEBOX_RESULT] = &[IDOK, IDCANCEL];
+
+    fn button_for(response: Self::Return) -> DefaultButton {
+        match response {
+            OkCancelResponse::Ok => DefaultButton::First,
+            OkCancelResponse::Cancel => DefaultButton::Second,
+        }
+    }
 }
 
 impl From<OkCancel> for MESSAGEBOX_STYLE {
@@ -79,15 +360,24 @@ impl TryFrom<MESSAGEBOX_RESULT> for OkCancelResponse {
         } else if value == IDCANCEL {
             OkCancelResponse::Cancel
         } else {
-            Err(crate::Error::UnknownResponseCode(value.0))?
+            Err(unexpected::<OkCancel>(value))?
         };
 
         Ok(converted)
     }
 }
 
+/// Converts the response back to the code that Windows reports for it, see
+/// [OkCancelResponse::as_code].
+impl From<OkCancelResponse> for MESSAGEBOX_RESULT {
+    fn from(value: OkCancelResponse) -> Self {
+        MESSAGEBOX_RESULT(value.as_code())
+    }
+}
+
 /// The possible return values for [OkCancel]
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OkCancelResponse {
     /// The user agreed to perform the action described by the message box's content.
     Ok,
@@ -95,6 +385,60 @@ pub enum OkCancelResponse {
     Cancel,
 }
 
+impl OkCancelResponse {
+    /// Returns true if the response is [OkCancelResponse::Ok].
+    pub fn is_ok(&self) -> bool {
+        matches!(self, OkCancelResponse::Ok)
+    }
+
+    /// Returns true if the response is [OkCancelResponse::Cancel].
+    pub fn is_cancel(&self) -> bool {
+        matches!(self, OkCancelResponse::Cancel)
+    }
+
+    /// Returns true if the user agreed to perform the action, i.e. clicked 'ok'.
+    pub fn is_affirmative(&self) -> bool {
+        matches!(self, OkCancelResponse::Ok)
+    }
+
+    /// Returns `Ok` if the user clicked 'ok', and [UserDeclined] with the actual
+    /// response otherwise. Allows bailing out with `?` unless the user agreed.
+    pub fn require_ok(self) -> Result<(), UserDeclined<Self>> {
+        match self {
+            OkCancelResponse::Ok => Ok(()),
+            other => Err(UserDeclined(other)),
+        }
+    }
+
+    /// The label of the button that produces this response, in lowercase, such as `"ok"`.
+    /// The English label is returned regardless of the language of the system. Used by
+    /// the [Display](std::fmt::Display) implementation and by comparisons with strings.
+    pub fn label(&self) -> &'static str {
+        match self {
+            OkCancelResponse::Ok => "ok",
+            OkCancelResponse::Cancel => "cancel",
+        }
+    }
+
+    /// The exit code of a command line tool that reports this response, following the
+    /// convention of [crate::AnyResponse::exit_code].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            OkCancelResponse::Ok => 0,
+            OkCancelResponse::Cancel => 1,
+        }
+    }
+
+    /// The response code that Windows reports for this response, such as `IDOK`. The
+    /// inverse of the [TryFrom] conversion from [MESSAGEBOX_RESULT].
+    pub fn as_code(&self) -> i32 {
+        match self {
+            OkCancelResponse::Ok => IDOK.0,
+            OkCancelResponse::Cancel => IDCANCEL.0,
+        }
+    }
+}
+
 /// Represents a dialog that requests user action in the case of an error. The user may choose
 /// to abort the action, retry it, or ignore the error. This is typically used when a sequence
 /// of actions are being carried out and one step encountered an error.
@@ -103,6 +447,22 @@ pub struct AbortRetryIgnore;
 
 impl DialogStyle for AbortRetryIgnore {
     type Return = AbortRetryIgnoreResponse;
+
+    const NAME: &'static str = "AbortRetryIgnore";
+
+    const BUTTON_COUNT: usize = 3;
+
+    const BUTTON_LABELS: &'static [&'static str] = &["Abort", "Retry", "Ignore"];
+
+    const BUTTON_IDS: &'static [MESSAGEBOX_RESULT] = &[IDABORT, IDRETRY, IDIGNORE];
+
+    fn button_for(response: Self::Return) -> DefaultButton {
+        match response {
+            AbortRetryIgnoreResponse::Abort => DefaultButton::First,
+            AbortRetryIgnoreResponse::Retry => DefaultButton::Second,
+            AbortRetryIgnoreResponse::Ignore => DefaultButton::Third,
+        }
+    }
 }
 
 impl From<AbortRetryIgnore> for MESSAGEBOX_STYLE {
@@ -112,7 +472,8 @@ impl From<AbortRetryIgnore> for MESSAGEBOX_STYLE {
 }
 
 /// The possible return values for [AbortRetryIgnore]
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AbortRetryIgnoreResponse {
     /// The user wants to give up performing the action.
     Abort,
@@ -122,6 +483,63 @@ pub enum AbortRetryIgnoreResponse {
     Ignore,
 }
 
+impl AbortRetryIgnoreResponse {
+    /// Returns true if the response is [AbortRetryIgnoreResponse::Abort].
+    pub fn is_abort(&self) -> bool {
+        matches!(self, AbortRetryIgnoreResponse::Abort)
+    }
+
+    /// Returns true if the response is [AbortRetryIgnoreResponse::Retry].
+    pub fn is_retry(&self) -> bool {
+        matches!(self, AbortRetryIgnoreResponse::Retry)
+    }
+
+    /// Returns true if the response is [AbortRetryIgnoreResponse::Ignore].
+    pub fn is_ignore(&self) -> bool {
+        matches!(self, AbortRetryIgnoreResponse::Ignore)
+    }
+
+    /// Returns true if the user wants the process to carry on, either by retrying the
+    /// failed action or by ignoring the error. Only 'abort' is not affirmative.
+    pub fn is_affirmative(&self) -> bool {
+        matches!(
+            self,
+            AbortRetryIgnoreResponse::Retry | AbortRetryIgnoreResponse::Ignore
+        )
+    }
+
+    /// The label of the button that produces this response, in lowercase, such as `"abort"`.
+    /// The English label is returned regardless of the language of the system. Used by
+    /// the [Display](std::fmt::Display) implementation and by comparisons with strings.
+    pub fn label(&self) -> &'static str {
+        match self {
+            AbortRetryIgnoreResponse::Abort => "abort",
+            AbortRetryIgnoreResponse::Retry => "retry",
+            AbortRetryIgnoreResponse::Ignore => "ignore",
+        }
+    }
+
+    /// The exit code of a command line tool that reports this response, following the
+    /// convention of [crate::AnyResponse::exit_code].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AbortRetryIgnoreResponse::Abort => 2,
+            AbortRetryIgnoreResponse::Retry => 3,
+            AbortRetryIgnoreResponse::Ignore => 4,
+        }
+    }
+
+    /// The response code that Windows reports for this response, such as `IDABORT`. The
+    /// inverse of the [TryFrom] conversion from [MESSAGEBOX_RESULT].
+    pub fn as_code(&self) -> i32 {
+        match self {
+            AbortRetryIgnoreResponse::Abort => IDABORT.0,
+            AbortRetryIgnoreResponse::Retry => IDRETRY.0,
+            AbortRetryIgnoreResponse::Ignore => IDIGNORE.0,
+        }
+    }
+}
+
 impl TryFrom<MESSAGEBOX_RESULT> for AbortRetryIgnoreResponse {
     type Error = crate::Error;
 
@@ -133,13 +551,21 @@ impl TryFrom<MESSAGEBOX_RESULT> for AbortRetryIgnoreResponse {
         } else if value == IDIGNORE {
             AbortRetryIgnoreResponse::Ignore
         } else {
-            Err(crate::Error::UnknownResponseCode(value.0))?
+            Err(unexpected::<AbortRetryIgnore>(value))?
         };
 
         Ok(converted)
     }
 }
 
+/// Converts the response back to the code that Windows reports for it, see
+/// [AbortRetryIgnoreResponse::as_code].
+impl From<AbortRetryIgnoreResponse> for MESSAGEBOX_RESULT {
+    fn from(value: AbortRetryIgnoreResponse) -> Self {
+        MESSAGEBOX_RESULT(value.as_code())
+    }
+}
+
 /// Represents a dialog where a user input is needed during an ongoing series of actions. The user may accept
 /// the next action, reject the action, or cancel the process entirely. It also featuers an X button
 /// in the top right, which results in the same response code as 'cancel'.
@@ -148,6 +574,22 @@ pub struct YesNoCancel;
 
 impl DialogStyle for YesNoCancel {
     type Return = YesNoCancelResponse;
+
+    const NAME: &'static str = "YesNoCancel";
+
+    const BUTTON_COUNT: usize = 3;
+
+    const BUTTON_LABELS: &'static [&'static str] = &["Yes", "No", "Cancel"];
+
+    const BUTTON_IDS: &'static [MESSAGEBOX_RESULT] = &[IDYES, IDNO, IDCANCEL];
+
+    fn button_for(response: Self::Return) -> DefaultButton {
+        match response {
+            YesNoCancelResponse::Yes => DefaultButton::First,
+            YesNoCancelResponse::No => DefaultButton::Second,
+            YesNoCancelResponse::Cancel => DefaultButton::Third,
+        }
+    }
 }
 
 impl From<YesNoCancel> for MESSAGEBOX_STYLE {
@@ -167,15 +609,24 @@ impl TryFrom<MESSAGEBOX_RESULT> for YesNoCancelResponse {
         } else if value == IDCANCEL {
             YesNoCancelResponse::Cancel
         } else {
-            Err(crate::Error::UnknownResponseCode(value.0))?
+            Err(unexpected::<YesNoCancel>(value))?
         };
 
         Ok(converted)
     }
 }
 
+/// Converts the response back to the code that Windows reports for it, see
+/// [YesNoCancelResponse::as_code].
+impl From<YesNoCancelResponse> for MESSAGEBOX_RESULT {
+    fn from(value: YesNoCancelResponse) -> Self {
+        MESSAGEBOX_RESULT(value.as_code())
+    }
+}
+
 /// Possible responses for [YesNoCancel]
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum YesNoCancelResponse {
     /// The user accepts the proposed action. Proceed to the next step in the series of actions.
     Yes,
@@ -185,6 +636,68 @@ pub enum YesNoCancelResponse {
     Cancel,
 }
 
+impl YesNoCancelResponse {
+    /// Returns true if the response is [YesNoCancelResponse::Yes].
+    pub fn is_yes(&self) -> bool {
+        matches!(self, YesNoCancelResponse::Yes)
+    }
+
+    /// Returns true if the response is [YesNoCancelResponse::No].
+    pub fn is_no(&self) -> bool {
+        matches!(self, YesNoCancelResponse::No)
+    }
+
+    /// Returns true if the response is [YesNoCancelResponse::Cancel].
+    pub fn is_cancel(&self) -> bool {
+        matches!(self, YesNoCancelResponse::Cancel)
+    }
+
+    /// Returns true if the user accepted the proposed action, i.e. clicked 'yes'.
+    pub fn is_affirmative(&self) -> bool {
+        matches!(self, YesNoCancelResponse::Yes)
+    }
+
+    /// Returns `Ok` if the user clicked 'yes', and [UserDeclined] with the actual
+    /// response otherwise. Allows bailing out with `?` unless the user agreed.
+    pub fn require_yes(self) -> Result<(), UserDeclined<Self>> {
+        match self {
+            YesNoCancelResponse::Yes => Ok(()),
+            other => Err(UserDeclined(other)),
+        }
+    }
+
+    /// The label of the button that produces this response, in lowercase, such as `"yes"`.
+    /// The English label is returned regardless of the language of the system. Used by
+    /// the [Display](std::fmt::Display) implementation and by comparisons with strings.
+    pub fn label(&self) -> &'static str {
+        match self {
+            YesNoCancelResponse::Yes => "yes",
+            YesNoCancelResponse::No => "no",
+            YesNoCancelResponse::Cancel => "cancel",
+        }
+    }
+
+    /// The exit code of a command line tool that reports this response, following the
+    /// convention of [crate::AnyResponse::exit_code].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            YesNoCancelResponse::Yes => 0,
+            YesNoCancelResponse::No => 5,
+            YesNoCancelResponse::Cancel => 1,
+        }
+    }
+
+    /// The response code that Windows reports for this response, such as `IDYES`. The
+    /// inverse of the [TryFrom] conversion from [MESSAGEBOX_RESULT].
+    pub fn as_code(&self) -> i32 {
+        match self {
+            YesNoCancelResponse::Yes => IDYES.0,
+            YesNoCancelResponse::No => IDNO.0,
+            YesNoCancelResponse::Cancel => IDCANCEL.0,
+        }
+    }
+}
+
 /// Displays a dialog with only two buttons, yes and no. Used in cases where there is only as single
 /// action to be performed.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -192,6 +705,21 @@ pub struct YesNo;
 
 impl DialogStyle for YesNo {
     type Return = YesNoResponse;
+
+    const NAME: &'static str = "YesNo";
+
+    const BUTTON_COUNT: usize = 2;
+
+    const BUTTON_LABELS: &'static [&'static str] = &["Yes", "No"];
+
+    const BUTTON_IDS: &'static [MESSAGEBOX_RESULT] = &[IDYES, IDNO];
+
+    fn button_for(response: Self::Return) -> DefaultButton {
+        match response {
+            YesNoResponse::Yes => DefaultButton::First,
+            YesNoResponse::No => DefaultButton::Second,
+        }
+    }
 }
 
 impl From<YesNo> for MESSAGEBOX_STYLE {
@@ -201,7 +729,8 @@ impl From<YesNo> for MESSAGEBOX_STYLE {
 }
 
 /// Possible resonses to [YesNo]
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum YesNoResponse {
     /// The user accepts the proposed action.
     Yes,
@@ -209,6 +738,80 @@ pub enum YesNoResponse {
     No,
 }
 
+impl YesNoResponse {
+    /// Returns true if the response is [YesNoResponse::Yes].
+    pub fn is_yes(&self) -> bool {
+        matches!(self, YesNoResponse::Yes)
+    }
+
+    /// Returns true if the response is [YesNoResponse::No].
+    pub fn is_no(&self) -> bool {
+        matches!(self, YesNoResponse::No)
+    }
+
+    /// Returns true if the user accepted the proposed action, i.e. clicked 'yes'.
+    pub fn is_affirmative(&self) -> bool {
+        matches!(self, YesNoResponse::Yes)
+    }
+
+    /// Returns `Ok` if the user clicked 'yes', and [UserDeclined] with the actual
+    /// response otherwise. Allows bailing out with `?` unless the user agreed.
+    ///
+    /// ```no_run
+    /// use win_dialog::{style::YesNo, WinDialog};
+    ///
+    /// fn delete_all() -> Result<(), Box<dyn std::error::Error>> {
+    ///     WinDialog::new("Delete all files?")
+    ///         .with_style(YesNo)
+    ///         .show()?
+    ///         .require_yes()?;
+    ///     // Only reached if the user clicked 'yes'.
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn require_yes(self) -> Result<(), UserDeclined<Self>> {
+        match self {
+            YesNoResponse::Yes => Ok(()),
+            other => Err(UserDeclined(other)),
+        }
+    }
+
+    /// The label of the button that produces this response, in lowercase, such as `"yes"`.
+    /// The English label is returned regardless of the language of the system. Used by
+    /// the [Display](std::fmt::Display) implementation and by comparisons with strings.
+    ///
+    /// ```rust
+    /// use win_dialog::style::YesNoResponse;
+    ///
+    /// assert_eq!(YesNoResponse::Yes, "yes");
+    /// assert_ne!(YesNoResponse::No, "yes");
+    /// ```
+    pub fn label(&self) -> &'static str {
+        match self {
+            YesNoResponse::Yes => "yes",
+            YesNoResponse::No => "no",
+        }
+    }
+
+    /// The exit code of a command line tool that reports this response, following the
+    /// convention of [crate::AnyResponse::exit_code].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            YesNoResponse::Yes => 0,
+            YesNoResponse::No => 5,
+        }
+    }
+
+    /// The response code that Windows reports for this response, such as `IDYES`. The
+    /// inverse of the [TryFrom] conversion from [MESSAGEBOX_RESULT].
+    pub fn as_code(&self) -> i32 {
+        match self {
+            YesNoResponse::Yes => IDYES.0,
+            YesNoResponse::No => IDNO.0,
+        }
+    }
+}
+
 impl TryFrom<MESSAGEBOX_RESULT> for YesNoResponse {
     type Error = crate::Error;
 
@@ -218,13 +821,21 @@ impl TryFrom<MESSAGEBOX_RESULT> for YesNoResponse {
         } else if value == IDNO {
             YesNoResponse::No
         } else {
-            Err(crate::Error::UnknownResponseCode(value.0))?
+            Err(unexpected::<YesNo>(value))?
         };
 
         Ok(converted)
     }
 }
 
+/// Converts the response back to the code that Windows reports for it, see
+/// [YesNoResponse::as_code].
+impl From<YesNoResponse> for MESSAGEBOX_RESULT {
+    fn from(value: YesNoResponse) -> Self {
+        MESSAGEBOX_RESULT(value.as_code())
+    }
+}
+
 /// Presents two buttons: retry or cancel. It also has an X button at the top right, which
 /// returns the same response as 'cancel'. Use in cases where only a single action occurs
 /// rather than a sequence of actions.
@@ -233,6 +844,21 @@ pub struct RetryCancel;
 
 impl DialogStyle for RetryCancel {
     type Return = RetryCancelResponse;
+
+    const NAME: &'static str = "RetryCancel";
+
+    const BUTTON_COUNT: usize = 2;
+
+    const BUTTON_LABELS: &'static [&'static str] = &["Retry", "Cancel"];
+
+    const BUTTON_IDS: &'static [MESSAGEBOX_RESULT] = &[IDRETRY, IDCANCEL];
+
+    fn button_for(response: Self::Return) -> DefaultButton {
+        match response {
+            RetryCancelResponse::Retry => DefaultButton::First,
+            RetryCancelResponse::Cancel => DefaultButton::Second,
+        }
+    }
 }
 
 impl From<RetryCancel> for MESSAGEBOX_STYLE {
@@ -242,7 +868,8 @@ impl From<RetryCancel> for MESSAGEBOX_STYLE {
 }
 
 /// Possible responses for [RetryCancel]
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RetryCancelResponse {
     /// The user indicated a desire to try the operation again.
     Retry,
@@ -250,6 +877,51 @@ pub enum RetryCancelResponse {
     Cancel,
 }
 
+impl RetryCancelResponse {
+    /// Returns true if the response is [RetryCancelResponse::Retry].
+    pub fn is_retry(&self) -> bool {
+        matches!(self, RetryCancelResponse::Retry)
+    }
+
+    /// Returns true if the response is [RetryCancelResponse::Cancel].
+    pub fn is_cancel(&self) -> bool {
+        matches!(self, RetryCancelResponse::Cancel)
+    }
+
+    /// Returns true if the user wants to try the operation again, i.e. clicked 'retry'.
+    pub fn is_affirmative(&self) -> bool {
+        matches!(self, RetryCancelResponse::Retry)
+    }
+
+    /// The label of the button that produces this response, in lowercase, such as `"retry"`.
+    /// The English label is returned regardless of the language of the system. Used by
+    /// the [Display](std::fmt::Display) implementation and by comparisons with strings.
+    pub fn label(&self) -> &'static str {
+        match self {
+            RetryCancelResponse::Retry => "retry",
+            RetryCancelResponse::Cancel => "cancel",
+        }
+    }
+
+    /// The exit code of a command line tool that reports this response, following the
+    /// convention of [crate::AnyResponse::exit_code].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            RetryCancelResponse::Retry => 3,
+            RetryCancelResponse::Cancel => 1,
+        }
+    }
+
+    /// The response code that Windows reports for this response, such as `IDRETRY`. The
+    /// inverse of the [TryFrom] conversion from [MESSAGEBOX_RESULT].
+    pub fn as_code(&self) -> i32 {
+        match self {
+            RetryCancelResponse::Retry => IDRETRY.0,
+            RetryCancelResponse::Cancel => IDCANCEL.0,
+        }
+    }
+}
+
 impl TryFrom<MESSAGEBOX_RESULT> for RetryCancelResponse {
     type Error = crate::Error;
 
@@ -259,13 +931,21 @@ impl TryFrom<MESSAGEBOX_RESULT> for RetryCancelResponse {
         } else if value == IDCANCEL {
             RetryCancelResponse::Cancel
         } else {
-            Err(crate::Error::UnknownResponseCode(value.0))?
+            Err(unexpected::<RetryCancel>(value))?
         };
 
         Ok(converted)
     }
 }
 
+/// Converts the response back to the code that Windows reports for it, see
+/// [RetryCancelResponse::as_code].
+impl From<RetryCancelResponse> for MESSAGEBOX_RESULT {
+    fn from(value: RetryCancelResponse) -> Self {
+        MESSAGEBOX_RESULT(value.as_code())
+    }
+}
+
 /// Presents three buttons: retry, cancel, and continue. Continue should indicate skipping
 /// a failed action but continuing the overarching process.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -273,6 +953,22 @@ pub struct CancelRetryContinue;
 
 impl DialogStyle for CancelRetryContinue {
     type Return = CancelRetryContinueResponse;
+
+    const NAME: &'static str = "CancelRetryContinue";
+
+    const BUTTON_COUNT: usize = 3;
+
+    const BUTTON_LABELS: &'static [&'static str] = &["Cancel", "Try Again", "Continue"];
+
+    const BUTTON_IDS: &'static [MESSAGEBOX_RESULT] = &[IDCANCEL, IDTRYAGAIN, IDCONTINUE];
+
+    fn button_for(response: Self::Return) -> DefaultButton {
+        match response {
+            CancelRetryContinueResponse::Cancel => DefaultButton::First,
+            CancelRetryContinueResponse::Retry => DefaultButton::Second,
+            CancelRetryContinueResponse::Continue => DefaultButton::Third,
+        }
+    }
 }
 
 impl From<CancelRetryContinue> for MESSAGEBOX_STYLE {
@@ -282,7 +978,8 @@ impl From<CancelRetryContinue> for MESSAGEBOX_STYLE {
 }
 
 /// Possile responses to [CancelRetryContinue]
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CancelRetryContinueResponse {
     /// The user indicates a desire to abandon the sequences of actions entirely.
     Cancel,
@@ -292,20 +989,94 @@ pub enum CancelRetryContinueResponse {
     Continue,
 }
 
+impl CancelRetryContinueResponse {
+    /// Returns true if the response is [CancelRetryContinueResponse::Cancel].
+    pub fn is_cancel(&self) -> bool {
+        matches!(self, CancelRetryContinueResponse::Cancel)
+    }
+
+    /// Returns true if the response is [CancelRetryContinueResponse::Retry].
+    pub fn is_retry(&self) -> bool {
+        matches!(self, CancelRetryContinueResponse::Retry)
+    }
+
+    /// Returns true if the response is [CancelRetryContinueResponse::Continue].
+    pub fn is_continue(&self) -> bool {
+        matches!(self, CancelRetryContinueResponse::Continue)
+    }
+
+    /// Returns true if the user wants the process to carry on, either by retrying the
+    /// failed action or by continuing with the next one. Only 'cancel' is not affirmative.
+    pub fn is_affirmative(&self) -> bool {
+        matches!(
+            self,
+            CancelRetryContinueResponse::Retry | CancelRetryContinueResponse::Continue
+        )
+    }
+
+    /// The label of the button that produces this response, in lowercase, such as `"cancel"`.
+    /// The English label is returned regardless of the language of the system. Used by
+    /// the [Display](std::fmt::Display) implementation and by comparisons with strings.
+    ///
+    /// The button of [CancelRetryContinueResponse::Retry] reads 'Try Again', but its label
+    /// is `"retry"`, the same as that of [crate::AnyResponse::Retry], so the response
+    /// compares equal to the same string whether the dialog was shown with
+    /// [crate::WinDialog::show] or [crate::WinDialog::show_any].
+    pub fn label(&self) -> &'static str {
+        match self {
+            CancelRetryContinueResponse::Cancel => "cancel",
+            CancelRetryContinueResponse::Retry => "retry",
+            CancelRetryContinueResponse::Continue => "continue",
+        }
+    }
+
+    /// The exit code of a command line tool that reports this response, following the
+    /// convention of [crate::AnyResponse::exit_code].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CancelRetryContinueResponse::Cancel => 1,
+            CancelRetryContinueResponse::Retry => 3,
+            CancelRetryContinueResponse::Continue => 0,
+        }
+    }
+
+    /// The response code that Windows reports for this response, such as `IDCANCEL`. The
+    /// inverse of the [TryFrom] conversion from [MESSAGEBOX_RESULT]. The 'try again'
+    /// button is reported as `IDTRYAGAIN`.
+    pub fn as_code(&self) -> i32 {
+        match self {
+            CancelRetryContinueResponse::Cancel => IDCANCEL.0,
+            CancelRetryContinueResponse::Retry => IDTRYAGAIN.0,
+            CancelRetryContinueResponse::Continue => IDCONTINUE.0,
+        }
+    }
+}
+
 impl TryFrom<MESSAGEBOX_RESULT> for CancelRetryContinueResponse {
     type Error = crate::Error;
 
     fn try_from(value: MESSAGEBOX_RESULT) -> Result<Self, Self::Error> {
-        let converted = if value == IDRETRY {
+        // Windows only ever reports the 'try again' button as IDTRYAGAIN. IDRETRY is still
+        // accepted because earlier versions of this crate expected it, so tests that inject
+        // it with the mock feature keep working.
+        let converted = if value == IDTRYAGAIN || value == IDRETRY {
             CancelRetryContinueResponse::Retry
         } else if value == IDCANCEL {
             CancelRetryContinueResponse::Cancel
         } else if value == IDCONTINUE {
             CancelRetryContinueResponse::Continue
         } else {
-            Err(crate::Error::UnknownResponseCode(value.0))?
+            Err(unexpected::<CancelRetryContinue>(value))?
         };
 
         Ok(converted)
     }
 }
+
+/// Converts the response back to the code that Windows reports for it, see
+/// [CancelRetryContinueResponse::as_code].
+impl From<CancelRetryContinueResponse> for MESSAGEBOX_RESULT {
+    fn from(value: CancelRetryContinueResponse) -> Self {
+        MESSAGEBOX_RESULT(value.as_code())
+    }
+}
@@ -0,0 +1,1429 @@
+#[cfg(all(windows, not(feature = "mock")))]
+use std::cell::Cell;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::atomic::AtomicBool;
+#[cfg(any(windows, feature = "mock"))]
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::core::HRESULT;
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::core::PCWSTR;
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::Foundation::{BOOL, E_FAIL, LPARAM, LRESULT, RECT, S_OK, WPARAM};
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::UI::Controls::{
+    TaskDialogIndirect, TASKDIALOGCONFIG, TASKDIALOGCONFIG_0, TASKDIALOGCONFIG_1,
+    TASKDIALOG_BUTTON, TASKDIALOG_FLAGS, TASKDIALOG_NOTIFICATIONS, TDF_CALLBACK_TIMER,
+    TDF_ENABLE_HYPERLINKS, TDF_EXPANDED_BY_DEFAULT, TDF_NO_DEFAULT_RADIO_BUTTON,
+    TDF_POSITION_RELATIVE_TO_WINDOW, TDF_RTL_LAYOUT, TDF_SHOW_MARQUEE_PROGRESS_BAR,
+    TDF_SHOW_PROGRESS_BAR, TDF_USE_COMMAND_LINKS, TDF_USE_HICON_MAIN,
+    TDM_SET_BUTTON_ELEVATION_REQUIRED_STATE, TDN_CREATED, TDN_DESTROYED, TDN_DIALOG_CONSTRUCTED,
+    TDN_HELP, TDN_HYPERLINK_CLICKED, TDN_TIMER,
+};
+#[cfg(all(windows, not(feature = "mock")))]
+use windows::Win32::UI::WindowsAndMessaging::{
+    EnableMenuItem, EndDialog, GetSystemMenu, GetWindowRect, LoadIconW, SendMessageW, ShowWindow,
+    IDI_ERROR, IDI_INFORMATION, IDI_SHIELD, IDI_WARNING, MF_BYCOMMAND, MF_GRAYED, SC_CLOSE,
+    SW_SHOWNOACTIVATE, SW_SHOWNORMAL, WM_SYSCOMMAND,
+};
+
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::Controls::{
+    TASKDIALOG_COMMON_BUTTON_FLAGS, TDCBF_CANCEL_BUTTON, TDCBF_CLOSE_BUTTON, TDCBF_NO_BUTTON,
+    TDCBF_OK_BUTTON, TDCBF_RETRY_BUTTON, TDCBF_YES_BUTTON,
+};
+use windows::Win32::UI::WindowsAndMessaging::{
+    HICON, IDCANCEL, IDCLOSE, IDNO, IDOK, IDRETRY, IDYES,
+};
+
+use crate::dialog::to_wide;
+use crate::icon::Icon;
+#[cfg(all(windows, not(feature = "mock")))]
+use crate::language::is_rtl_language;
+use crate::language::ThreadLanguage;
+#[cfg(all(windows, not(feature = "mock")))]
+use crate::position::{
+    align_buttons_right, center_on_monitor, center_on_primary_monitor, make_topmost,
+};
+use crate::progress::ProgressController;
+
+/// A closure that the callback of a [WinTaskDialog] invokes while the dialog is displayed.
+/// Clones of the dialog share the closure.
+struct Handler<F: ?Sized>(Arc<Mutex<F>>);
+
+impl<F: ?Sized> Handler<F> {
+    /// Gives access to the closure. A closure that panicked before is still invoked.
+    #[cfg_attr(any(not(windows), feature = "mock"), allow(dead_code))]
+    fn lock(&self) -> MutexGuard<'_, F> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<F: ?Sized> Clone for Handler<F> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> Debug for Handler<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Handler")
+    }
+}
+
+impl<F: ?Sized> PartialEq for Handler<F> {
+    /// Handlers are equal if they belong to clones of the same dialog.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The closure set with [WinTaskDialog::on_help].
+type HelpHandler = Handler<dyn FnMut() + Send>;
+
+/// The closure set with [WinTaskDialog::on_hyperlink].
+type HyperlinkHandler = Handler<dyn FnMut(&str) + Send>;
+
+/// The flag set with [WinTaskDialog::dismiss_on].
+#[derive(Debug, Clone)]
+struct DismissFlag(Arc<AtomicBool>);
+
+impl PartialEq for DismissFlag {
+    /// Flags are equal if they are clones of each other.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A button with custom text, displayed by a [WinTaskDialog].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomButton {
+    /// The value returned by [WinTaskDialog::show] when the user clicks this button.
+    pub id: i32,
+    /// The text displayed on the button.
+    pub text: String,
+}
+
+impl CustomButton {
+    /// Create a new button with the given id and text.
+    pub fn new(id: i32, text: impl Into<String>) -> Self {
+        Self {
+            id,
+            text: text.into(),
+        }
+    }
+}
+
+/// The standard buttons of a [WinTaskDialog], whose labels Windows localizes. See
+/// [WinTaskDialog::override_label].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ButtonKind {
+    /// The 'ok' button, reported as [IDOK].
+    Ok,
+    /// The 'yes' button, reported as [IDYES].
+    Yes,
+    /// The 'no' button, reported as [IDNO].
+    No,
+    /// The 'cancel' button, reported as [IDCANCEL].
+    Cancel,
+    /// The 'retry' button, reported as [IDRETRY].
+    Retry,
+    /// The 'close' button, reported as [IDCLOSE].
+    Close,
+}
+
+impl ButtonKind {
+    /// All standard buttons, in the order that Windows displays them.
+    const ALL: [ButtonKind; 6] = [
+        ButtonKind::Ok,
+        ButtonKind::Yes,
+        ButtonKind::No,
+        ButtonKind::Retry,
+        ButtonKind::Cancel,
+        ButtonKind::Close,
+    ];
+
+    /// The flag that displays the button.
+    fn flag(self) -> TASKDIALOG_COMMON_BUTTON_FLAGS {
+        match self {
+            ButtonKind::Ok => TDCBF_OK_BUTTON,
+            ButtonKind::Yes => TDCBF_YES_BUTTON,
+            ButtonKind::No => TDCBF_NO_BUTTON,
+            ButtonKind::Cancel => TDCBF_CANCEL_BUTTON,
+            ButtonKind::Retry => TDCBF_RETRY_BUTTON,
+            ButtonKind::Close => TDCBF_CLOSE_BUTTON,
+        }
+    }
+
+    /// The id that is reported when the button is clicked.
+    fn id(self) -> i32 {
+        let id = match self {
+            ButtonKind::Ok => IDOK,
+            ButtonKind::Yes => IDYES,
+            ButtonKind::No => IDNO,
+            ButtonKind::Cancel => IDCANCEL,
+            ButtonKind::Retry => IDRETRY,
+            ButtonKind::Close => IDCLOSE,
+        };
+        id.0
+    }
+}
+
+/// A large button with a title and a description, displayed by a [WinTaskDialog] in
+/// place of regular buttons. See [WinTaskDialog::with_command_links].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandLink {
+    /// The value returned by [WinTaskDialog::show] when the user clicks this link.
+    pub id: i32,
+    /// The title of the link, displayed in large text.
+    pub label: String,
+    /// The description displayed in smaller text below the label. An empty note results
+    /// in only the label being displayed.
+    pub note: String,
+}
+
+impl CommandLink {
+    /// Create a new command link with the given id, label and note.
+    pub fn new(id: i32, label: impl Into<String>, note: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            note: note.into(),
+        }
+    }
+}
+
+impl From<CommandLink> for CustomButton {
+    /// Task dialogs treat everything after the first line of a command link's text as
+    /// its note.
+    fn from(link: CommandLink) -> Self {
+        let text = match link.note.is_empty() {
+            true => link.label,
+            false => format!("{}\n{}", link.label, link.note),
+        };
+        CustomButton::new(link.id, text)
+    }
+}
+
+/// An option in a group of radio buttons, displayed by a [WinTaskDialog] above its
+/// buttons. See [WinTaskDialog::with_radio_buttons].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadioButton {
+    /// The value reported in [TaskDialogResponse::radio] when this option is selected.
+    pub id: i32,
+    /// The text displayed next to the radio button.
+    pub label: String,
+}
+
+impl RadioButton {
+    /// Create a new radio button with the given id and label.
+    pub fn new(id: i32, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+        }
+    }
+}
+
+/// The result of showing a [WinTaskDialog].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TaskDialogResponse {
+    /// The id of the button that the user clicked.
+    pub response: i32,
+    /// Whether the verification checkbox was checked when the dialog closed. Always false
+    /// if no checkbox was added with [WinTaskDialog::with_verification].
+    pub verification_checked: bool,
+    /// The id of the radio button that was selected when the dialog closed. [None] if no
+    /// radio buttons were added with [WinTaskDialog::with_radio_buttons], or if none of
+    /// them was selected.
+    pub radio: Option<i32>,
+    /// Whether the dialog was closed by the program rather than by the user, because the
+    /// flag passed to [WinTaskDialog::dismiss_on] was set. [TaskDialogResponse::response]
+    /// is then [IDCANCEL](windows::Win32::UI::WindowsAndMessaging::IDCANCEL).
+    pub dismissed: bool,
+    /// Whether the user closed the dialog from its title bar, with the close button, the
+    /// system menu or Alt+F4, rather than by clicking one of its buttons. The task dialog
+    /// reports this as a click on 'cancel', so [TaskDialogResponse::response] is then
+    /// [IDCANCEL](windows::Win32::UI::WindowsAndMessaging::IDCANCEL). Pressing Esc counts as
+    /// clicking 'cancel'. Always false with the `mock` feature enabled.
+    ///
+    /// The MessageBox api returns the same code for closing the dialog and for clicking
+    /// 'cancel', so this distinction is not available for a [crate::WinDialog].
+    pub closed_via_x: bool,
+}
+
+impl TaskDialogResponse {
+    /// Returns true if the user clicked the 'cancel' button or closed the dialog. See
+    /// [TaskDialogResponse::closed_via_x] to tell the two apart.
+    pub fn was_cancelled(&self) -> bool {
+        self.response == IDCANCEL.0 && !self.dismissed
+    }
+}
+
+/// A builder struct used for configuring a [Task Dialog](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-taskdialogindirect).
+/// Uses the TaskDialogIndirect function under the hood.
+///
+/// Task dialogs are the modern replacement for message boxes. Unlike [crate::WinDialog],
+/// they are not limited to a fixed set of buttons, so the buttons can be labeled with
+/// whatever text suits the action, such as "Save", "Discard" or "Keep Editing".
+///
+/// Task dialogs are implemented by version 6 of the Common Controls library. Applications
+/// must opt into that version with an
+/// [application manifest](https://learn.microsoft.com/en-us/windows/win32/controls/cookbook-overview),
+/// otherwise showing the dialog fails.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WinTaskDialog {
+    /// The text of the title bar. Passing nothing results in the executable name being used.
+    title: Option<String>,
+
+    /// The heading displayed above the body text. Providing no instruction results in no
+    /// heading being displayed.
+    instruction: Option<String>,
+
+    /// The body text of the task dialog.
+    content: String,
+
+    /// The icon that you want to display. Providing no icon results in no icon
+    /// being displayed.
+    icon: Option<Icon>,
+
+    /// A custom icon that is displayed instead of [WinTaskDialog::icon].
+    custom_icon: Option<HICON>,
+
+    /// Whether the sound associated with the icon should be suppressed.
+    silent: bool,
+
+    /// The buttons that the user can choose from. Providing no buttons results in a
+    /// single 'ok' button being displayed.
+    buttons: Vec<CustomButton>,
+
+    /// The id of the button that is selected when the dialog appears. Providing no id
+    /// results in the first button being selected.
+    default_button: Option<i32>,
+
+    /// The standard buttons, such as 'yes' and 'no', displayed alongside the custom ones.
+    /// Their labels are localized by Windows.
+    common_buttons: TASKDIALOG_COMMON_BUTTON_FLAGS,
+
+    /// Whether the buttons are displayed as command links.
+    command_links: bool,
+
+    /// The group of radio buttons that the user can pick an option from.
+    radio_buttons: Vec<RadioButton>,
+
+    /// The id of the radio button that is selected when the dialog appears. Providing no
+    /// id results in the first radio button being selected.
+    default_radio: Option<i32>,
+
+    /// Whether no radio button is selected when the dialog appears.
+    no_default_radio: bool,
+
+    /// The label of the verification checkbox. Providing no label results in no checkbox
+    /// being displayed.
+    verification: Option<String>,
+
+    /// The text displayed in the footer area at the bottom of the task dialog. Providing
+    /// no footer results in no footer area being displayed.
+    footer: Option<String>,
+
+    /// The icon displayed next to the footer text.
+    footer_icon: Option<Icon>,
+
+    /// Additional information that is hidden until the user expands it. Providing no
+    /// information results in no expander being displayed.
+    expanded_info: Option<String>,
+
+    /// The labels of the expander button when collapsed and when expanded.
+    expander_labels: Option<(String, String)>,
+
+    /// Whether the additional information is visible when the dialog appears.
+    start_expanded: bool,
+
+    /// The index of the monitor that the dialog is displayed on.
+    monitor: Option<usize>,
+
+    /// The window that owns the dialog. See [WinTaskDialog::set_parent_window].
+    parent: HWND,
+
+    /// Whether the dialog is centered on its parent window. See
+    /// [WinTaskDialog::center_on_parent].
+    center_on_parent: bool,
+
+    /// Whether the dialog keeps re-asserting that it is topmost while it is displayed.
+    keep_topmost: bool,
+
+    /// Whether the dialog is displayed without taking the keyboard focus.
+    no_activate: bool,
+
+    /// Whether the buttons stay on the right in a mirrored layout. See
+    /// [WinTaskDialog::align_buttons_right].
+    buttons_right: bool,
+
+    /// The language of the standard buttons. See [WinTaskDialog::with_language].
+    language: Option<u16>,
+
+    /// Whether the close button in the title bar is disabled.
+    disable_close: bool,
+
+    /// Custom labels for standard buttons. See [WinTaskDialog::override_label].
+    label_overrides: Vec<(ButtonKind, String)>,
+
+    /// The ids of the buttons that display the shield overlay.
+    elevated_buttons: Vec<i32>,
+
+    /// Closes the dialog when set. See [WinTaskDialog::dismiss_on].
+    dismiss: Option<DismissFlag>,
+
+    /// Invoked when the user asks for help. See [WinTaskDialog::on_help].
+    on_help: Option<HelpHandler>,
+
+    /// Whether links in the text are rendered as hyperlinks.
+    hyperlinks: bool,
+
+    /// Invoked with the target of a hyperlink when the user clicks it.
+    on_hyperlink: Option<HyperlinkHandler>,
+
+    /// Controls the progress bar. Providing no controller results in no progress bar
+    /// being displayed.
+    progress: Option<ProgressController>,
+
+    /// Whether the progress bar scrolls continuously instead of showing a position.
+    marquee: bool,
+}
+
+impl WinTaskDialog {
+    /// Create a new task dialog with content only. This will wait indefinitely for user
+    /// input and will display a single 'ok' button.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Create a task dialog that asks the user a yes or no question. This is the
+    /// recommended replacement for a message box with the deprecated question mark icon:
+    /// the question is phrased as the main instruction, the detail explains it, and the
+    /// information icon is displayed.
+    ///
+    /// The dialog has the standard, localized 'yes' and 'no' buttons, which report
+    /// [IDYES](windows::Win32::UI::WindowsAndMessaging::IDYES) and
+    /// [IDNO](windows::Win32::UI::WindowsAndMessaging::IDNO) as the response.
+    ///
+    /// ```no_run
+    /// use win_dialog::WinTaskDialog;
+    /// use windows::Win32::UI::WindowsAndMessaging::IDYES;
+    ///
+    /// let response = WinTaskDialog::question(
+    ///     "Do you want to save your changes?",
+    ///     "Your changes will be lost if you don't save them.",
+    /// )
+    /// .show()
+    /// .unwrap();
+    ///
+    /// if response.response == IDYES.0 {
+    ///     // Save the changes.
+    /// }
+    /// ```
+    pub fn question(instruction: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            instruction: Some(instruction.into()),
+            content: detail.into(),
+            icon: Some(Icon::Information),
+            common_buttons: TDCBF_YES_BUTTON | TDCBF_NO_BUTTON,
+            ..Default::default()
+        }
+    }
+
+    /// Sets custom text for the title bar of the task dialog. Passing nothing results in
+    /// the executable name being used.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the heading displayed in the body of the task dialog, above the content. An
+    /// alias for [WinTaskDialog::with_instruction]. Note that [crate::WinDialog::with_header]
+    /// sets the title bar instead, since message boxes have no heading in their body; use
+    /// [WinTaskDialog::with_title] for the title bar of a task dialog.
+    pub fn with_header(self, header: impl Into<String>) -> Self {
+        self.with_instruction(header)
+    }
+
+    /// Set a main instruction for the task dialog. It is displayed as a prominent heading
+    /// above the body text, and typically states the question the dialog asks, leaving the
+    /// supporting detail to the content.
+    pub fn with_instruction(mut self, instruction: impl Into<String>) -> Self {
+        self.instruction = Some(instruction.into());
+        self
+    }
+
+    /// Set an [Icon] for the task dialog. Unlike message boxes, task dialogs display
+    /// [Icon::Shield], which marks prompts for actions that require administrator
+    /// privileges.
+    ///
+    /// ```no_run
+    /// use win_dialog::{Icon, WinTaskDialog};
+    ///
+    /// let response = WinTaskDialog::new("Changing the firewall requires administrator rights.")
+    ///     .with_instruction("Allow the app to change the firewall settings?")
+    ///     .with_icon(Icon::Shield)
+    ///     .show();
+    /// ```
+    pub fn with_icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Display a custom icon, such as one loaded from the application's resources, instead
+    /// of one of the system icons. Takes precedence over [WinTaskDialog::with_icon].
+    ///
+    /// The dialog does not take ownership of the handle, so it must remain valid until
+    /// [WinTaskDialog::show] returns, and it is up to the caller to destroy it afterwards.
+    pub fn with_custom_icon(mut self, icon: HICON) -> Self {
+        self.custom_icon = Some(icon);
+        self
+    }
+
+    /// Suppress the system sound that Windows plays when a dialog with one of the system
+    /// icons appears. The icon is still displayed, but is drawn from the icon handle of the
+    /// system icon rather than from the identifier that triggers the sound. Has no effect
+    /// when no icon, or a custom icon, is set.
+    pub fn set_silent(mut self) -> Self {
+        self.silent = true;
+        self
+    }
+
+    /// Set the buttons that the user can choose from. The buttons are displayed in the
+    /// order provided, and the id of the clicked button is returned by [WinTaskDialog::show].
+    /// Replaces any links set with [WinTaskDialog::with_command_links].
+    pub fn with_buttons(mut self, buttons: Vec<CustomButton>) -> Self {
+        self.buttons = buttons;
+        self.command_links = false;
+        self
+    }
+
+    /// Set command links for the user to choose from. Command links are large buttons
+    /// stacked vertically in the body of the dialog, each with a title and an optional
+    /// description, which suits dialogs that offer several distinct courses of action.
+    /// The id of the clicked link is returned by [WinTaskDialog::show]. Replaces any
+    /// buttons set with [WinTaskDialog::with_buttons].
+    pub fn with_command_links(mut self, links: Vec<CommandLink>) -> Self {
+        self.buttons = links.into_iter().map(CustomButton::from).collect();
+        self.command_links = true;
+        self
+    }
+
+    /// Select the button with the given id when the dialog appears, so that pressing
+    /// 'enter' clicks it, rather than the first one.
+    pub fn default_button(mut self, id: i32) -> Self {
+        self.default_button = Some(id);
+        self
+    }
+
+    /// Add a group of radio buttons for the user to pick one option from. Unlike buttons
+    /// and command links, selecting a radio button does not close the dialog; the selected
+    /// option is reported in [TaskDialogResponse::radio] along with the clicked button.
+    pub fn with_radio_buttons(mut self, radio_buttons: Vec<RadioButton>) -> Self {
+        self.radio_buttons = radio_buttons;
+        self
+    }
+
+    /// Select the radio button with the given id when the dialog appears, rather than the
+    /// first one.
+    pub fn default_radio(mut self, id: i32) -> Self {
+        self.default_radio = Some(id);
+        self.no_default_radio = false;
+        self
+    }
+
+    /// Select none of the radio buttons when the dialog appears, so the user has to make
+    /// an explicit choice.
+    pub fn no_default_radio(mut self) -> Self {
+        self.default_radio = None;
+        self.no_default_radio = true;
+        self
+    }
+
+    /// Add a checkbox with the given label below the buttons, such as "Don't ask me again".
+    /// Whether the user checked it is reported in [TaskDialogResponse::verification_checked].
+    pub fn with_verification(mut self, text: impl Into<String>) -> Self {
+        self.verification = Some(text.into());
+        self
+    }
+
+    /// Add a footer area at the bottom of the task dialog for fine print, such as
+    /// "This action cannot be undone."
+    pub fn with_footer(mut self, text: impl Into<String>) -> Self {
+        self.footer = Some(text.into());
+        self
+    }
+
+    /// Set an [Icon] to display next to the footer text. Has no effect unless a footer is
+    /// set with [WinTaskDialog::with_footer]. Unlike the main icon, the footer icon does
+    /// not play a sound.
+    pub fn with_footer_icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.footer_icon = Some(icon.into());
+        self
+    }
+
+    /// Add information that is hidden behind an expander button, such as the details of
+    /// an error. The user can reveal it on demand, which keeps the dialog uncluttered.
+    pub fn with_expanded_info(mut self, text: impl Into<String>) -> Self {
+        self.expanded_info = Some(text.into());
+        self
+    }
+
+    /// Set the labels of the expander button while the information is hidden and while
+    /// it is shown, such as "See details" and "Hide details". Windows provides default
+    /// labels otherwise. Has no effect unless [WinTaskDialog::with_expanded_info] is set.
+    pub fn with_expander_labels(
+        mut self,
+        collapsed: impl Into<String>,
+        expanded: impl Into<String>,
+    ) -> Self {
+        self.expander_labels = Some((collapsed.into(), expanded.into()));
+        self
+    }
+
+    /// Show the information set with [WinTaskDialog::with_expanded_info] when the dialog
+    /// appears, rather than waiting for the user to expand it.
+    pub fn start_expanded(mut self) -> Self {
+        self.start_expanded = true;
+        self
+    }
+
+    /// Display the dialog in the center of the given monitor, rather than on the monitor of
+    /// its owner. Monitors are counted from zero in the order in which Windows enumerates
+    /// them, and an index without a monitor falls back to the primary monitor.
+    pub fn with_monitor(mut self, index: usize) -> Self {
+        self.monitor = Some(index);
+        self
+    }
+
+    /// Set the window that owns the dialog. The owner is disabled while the dialog is
+    /// displayed, the same way [crate::WinDialog::set_parent_window] makes a message box
+    /// modal to its owner. The parent window must belong to the thread that shows the
+    /// dialog.
+    pub fn set_parent_window(mut self, parent: HWND) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Display the dialog in the center of the window set with
+    /// [WinTaskDialog::set_parent_window], rather than wherever Windows places it, which
+    /// may be on another monitor than the parent. Without a parent window, the dialog is
+    /// centered in the work area of the primary monitor instead. Has no effect when a
+    /// monitor is chosen with [WinTaskDialog::with_monitor].
+    pub fn center_on_parent(mut self) -> Self {
+        self.center_on_parent = true;
+        self
+    }
+
+    /// Keep the dialog above all other windows for as long as it is displayed, which suits
+    /// alerts that must not be missed. [crate::WinDialog::set_topmost] only makes a message
+    /// box topmost when it appears, so other topmost windows that are activated afterwards
+    /// can still cover it. This option instead re-asserts the topmost position when the
+    /// dialog is created, and again on every timer tick, roughly every 200 milliseconds.
+    pub fn keep_topmost(mut self) -> Self {
+        self.keep_topmost = true;
+        self
+    }
+
+    /// Display the dialog without activating it, so that it does not take the keyboard
+    /// focus away from the window the user is typing in. Suits background notifications.
+    ///
+    /// Unlike message boxes, task dialogs offer neither [crate::WinDialog::set_foreground]
+    /// nor [crate::WinDialog::set_topmost], which would activate the window. Combining this
+    /// option with [WinTaskDialog::keep_topmost] is fine: the dialog stays on top, but
+    /// still does not take the focus.
+    pub fn set_no_activate(mut self) -> Self {
+        self.no_activate = true;
+        self
+    }
+
+    /// Keep the buttons on the right edge of the dialog, even when the layout of the dialog is
+    /// mirrored for a right-to-left language, for example after
+    /// [SetProcessDefaultLayout](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setprocessdefaultlayout)
+    /// with `LAYOUT_RTL`. Task dialogs with a left-to-right layout already display their
+    /// buttons on the right, so this has no effect on them. The buttons are moved when the
+    /// dialog appears, and the text keeps the alignment of the layout.
+    ///
+    /// This is unrelated to [crate::WinDialog::set_right_justify], which right-justifies
+    /// the text of a message box. Message boxes offer no control over their buttons, which
+    /// follow the layout of the message box, while task dialogs can align their buttons
+    /// and their text separately.
+    pub fn align_buttons_right(mut self) -> Self {
+        self.buttons_right = true;
+        self
+    }
+
+    /// Display the standard buttons, and other text that Windows provides, in the given
+    /// language, regardless of the language of the system. For Hebrew and Arabic, the
+    /// dialog is laid out from right to left as well. The content and labels set on the
+    /// dialog are not translated. See [crate::WinDialog::with_language] for more
+    /// information.
+    pub fn with_language(mut self, language: u16) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Grey out the close button in the title bar, and disable closing the dialog from its
+    /// system menu or with Alt+F4, so that the user has to make an explicit choice with one
+    /// of the buttons. Only task dialogs support this; the close button of a message box
+    /// cannot be disabled.
+    pub fn disable_close(mut self) -> Self {
+        self.disable_close = true;
+        self
+    }
+
+    /// Display the standard button with the given label instead of the one that Windows
+    /// provides in the language of the system, for applications that need to be displayed
+    /// in a particular language regardless of it. The button still reports its usual id,
+    /// such as [IDOK] for [ButtonKind::Ok]. The button is displayed even if it was not
+    /// added to the dialog before, and calling this again for the same button replaces
+    /// the label.
+    ///
+    /// This deliberately diverges from the localization of the system, so other
+    /// standard buttons, and the dialogs of other applications, keep the system's
+    /// language. Relabelled buttons are displayed as custom buttons, after those set with
+    /// [WinTaskDialog::with_buttons], and become command links as well if
+    /// [WinTaskDialog::with_command_links] is used.
+    ///
+    /// ```no_run
+    /// use win_dialog::{ButtonKind, WinTaskDialog};
+    ///
+    /// let response = WinTaskDialog::new("¿Desea guardar los cambios?")
+    ///     .override_label(ButtonKind::Yes, "Sí")
+    ///     .override_label(ButtonKind::No, "No")
+    ///     .show();
+    /// ```
+    pub fn override_label(mut self, button: ButtonKind, label: impl Into<String>) -> Self {
+        self.label_overrides.retain(|(kind, _)| *kind != button);
+        self.label_overrides.push((button, label.into()));
+        self
+    }
+
+    /// Display the shield overlay on the button with the given id, to indicate that
+    /// clicking it starts an action that requires administrator privileges. This is purely
+    /// cosmetic: the program still has to request the elevation itself. Can be called
+    /// multiple times to mark several buttons.
+    ///
+    /// The id must belong to one of the buttons of the dialog: a custom button, a command
+    /// link, or a standard button such as
+    /// [IDYES](windows::Win32::UI::WindowsAndMessaging::IDYES). Otherwise showing the
+    /// dialog fails with [crate::Error::InvalidConfiguration].
+    pub fn require_elevation(mut self, button_id: i32) -> Self {
+        self.elevated_buttons.push(button_id);
+        self
+    }
+
+    /// Close the dialog as soon as the flag is set, from any thread, for example by a
+    /// watchdog or an automated test. The flag is checked when the dialog appears and
+    /// then on every timer tick, roughly every 200 milliseconds. A dialog that was closed
+    /// this way is reported with [TaskDialogResponse::dismissed].
+    ///
+    /// With the `mock` feature enabled, a dialog whose flag is already set when it is shown
+    /// is reported as dismissed, regardless of the injected response.
+    ///
+    /// ```no_run
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    /// use win_dialog::WinTaskDialog;
+    ///
+    /// let shutdown = Arc::new(AtomicBool::new(false));
+    /// let dialog = WinTaskDialog::new("Waiting for the device...").dismiss_on(shutdown.clone());
+    ///
+    /// std::thread::spawn(move || {
+    ///     std::thread::sleep(std::time::Duration::from_secs(10));
+    ///     shutdown.store(true, Ordering::Relaxed);
+    /// });
+    ///
+    /// let response = dialog.show().unwrap();
+    /// if response.dismissed {
+    ///     println!("Gave up waiting");
+    /// }
+    /// ```
+    pub fn dismiss_on(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.dismiss = Some(DismissFlag(flag));
+        self
+    }
+
+    /// Invoke the closure when the user presses F1 while the dialog is displayed, for
+    /// example to open the documentation in a browser. The dialog stays open. This is only
+    /// available for task dialogs: a message box sends its help request to the parent
+    /// window instead, see [crate::WinDialogWithParent::with_help_button].
+    pub fn on_help(mut self, on_help: impl FnMut() + Send + 'static) -> Self {
+        self.on_help = Some(Handler(Arc::new(Mutex::new(on_help))));
+        self
+    }
+
+    /// Render links written as `<a href="...">text</a>` in the content, the footer and the
+    /// expanded information as hyperlinks. Without this option the markup is displayed as
+    /// is. Clicking a link does nothing unless a handler is set with
+    /// [WinTaskDialog::on_hyperlink] or [WinTaskDialog::open_hyperlinks_in_browser].
+    ///
+    /// Only enable hyperlinks for text that you control, since any markup in text from
+    /// other sources becomes clickable as well.
+    pub fn enable_hyperlinks(mut self) -> Self {
+        self.hyperlinks = true;
+        self
+    }
+
+    /// Invoke the closure with the `href` of a hyperlink when the user clicks it. The
+    /// dialog stays open. Enables hyperlinks, see [WinTaskDialog::enable_hyperlinks].
+    ///
+    /// ```no_run
+    /// use win_dialog::WinTaskDialog;
+    ///
+    /// let response = WinTaskDialog::new(r#"The update failed. <a href="log">Show the log</a>"#)
+    ///     .on_hyperlink(|href| println!("The user clicked {href}"))
+    ///     .show();
+    /// ```
+    pub fn on_hyperlink(mut self, on_hyperlink: impl FnMut(&str) + Send + 'static) -> Self {
+        self.hyperlinks = true;
+        self.on_hyperlink = Some(Handler(Arc::new(Mutex::new(on_hyperlink))));
+        self
+    }
+
+    /// Open clicked hyperlinks with the default program for their target, which is the
+    /// browser for web addresses. Replaces any closure set with
+    /// [WinTaskDialog::on_hyperlink], and enables hyperlinks.
+    pub fn open_hyperlinks_in_browser(self) -> Self {
+        self.on_hyperlink(open_in_browser)
+    }
+
+    /// Display a progress bar, driven by the given controller, and a 'cancel' button for
+    /// the user to abort the operation. Use [TaskDialogResponse::was_cancelled] to find out
+    /// whether the user did so. See [ProgressController] for an example.
+    pub fn with_progress_bar(mut self, progress: ProgressController) -> Self {
+        self.progress = Some(progress);
+        self.marquee = false;
+        self.common_buttons |= TDCBF_CANCEL_BUTTON;
+        self
+    }
+
+    /// Display a marquee progress bar, which scrolls continuously to indicate an operation
+    /// of unknown length, and a 'cancel' button. The animation starts when the dialog
+    /// appears and can be halted with [ProgressController::stop]. Otherwise the same as
+    /// [WinTaskDialog::with_progress_bar].
+    pub fn with_marquee(mut self, progress: ProgressController) -> Self {
+        self.progress = Some(progress);
+        self.marquee = true;
+        self.common_buttons |= TDCBF_CANCEL_BUTTON;
+        self
+    }
+
+    /// Returns the width and height, in pixels, that the dialog window will have when it is
+    /// shown, for example to place a backdrop behind it. The dialog is laid out, but ended
+    /// before it is ever drawn, so nothing appears on screen. Fails with
+    /// [crate::Error::Unsupported] where dialogs cannot be displayed, including with the
+    /// `mock` feature enabled.
+    pub fn measure(self) -> crate::Result<(u32, u32)> {
+        let dialog = self.relabel_buttons();
+        dialog.check_elevated_buttons()?;
+        let text = EncodedText::new(&dialog)?;
+        let _language = dialog.language.map(ThreadLanguage::set).transpose()?;
+        measure_dialog(&dialog, &text)
+    }
+
+    /// Display the task dialog and return the id of the button that the user clicked,
+    /// along with the state of the verification checkbox. This is a synchronous action.
+    pub fn show(self) -> crate::Result<TaskDialogResponse> {
+        let dialog = self.relabel_buttons();
+        dialog.check_elevated_buttons()?;
+        let text = EncodedText::new(&dialog)?;
+        let _language = dialog.language.map(ThreadLanguage::set).transpose()?;
+        task_dialog(&dialog, &text)
+    }
+}
+
+impl WinTaskDialog {
+    /// Replaces the standard buttons whose labels were overridden with custom buttons that
+    /// report the same id. See [WinTaskDialog::override_label].
+    fn relabel_buttons(mut self) -> Self {
+        if self.label_overrides.is_empty() {
+            return self;
+        }
+
+        // Without any buttons, Windows displays a single 'ok' button.
+        if self.buttons.is_empty() && self.common_buttons.0 == 0 {
+            self.common_buttons = TDCBF_OK_BUTTON;
+        }
+
+        let mut overrides = std::mem::take(&mut self.label_overrides);
+        for kind in ButtonKind::ALL {
+            if let Some(index) = overrides.iter().position(|(other, _)| *other == kind) {
+                let (_, label) = overrides.swap_remove(index);
+                self.common_buttons.0 &= !kind.flag().0;
+                self.buttons.push(CustomButton::new(kind.id(), label));
+            }
+        }
+
+        self
+    }
+
+    /// Whether the dialog displays a button with the given id.
+    fn has_button(&self, id: i32) -> bool {
+        let common = [
+            (TDCBF_OK_BUTTON, IDOK),
+            (TDCBF_YES_BUTTON, IDYES),
+            (TDCBF_NO_BUTTON, IDNO),
+            (TDCBF_CANCEL_BUTTON, IDCANCEL),
+            (TDCBF_RETRY_BUTTON, IDRETRY),
+            (TDCBF_CLOSE_BUTTON, IDCLOSE),
+        ];
+
+        // Without any buttons, Windows displays a single 'ok' button.
+        if self.buttons.is_empty() && self.common_buttons.0 == 0 {
+            return id == IDOK.0;
+        }
+
+        self.buttons.iter().any(|button| button.id == id)
+            || common
+                .iter()
+                .any(|(flag, result)| self.common_buttons.0 & flag.0 != 0 && result.0 == id)
+    }
+
+    /// Fails if [WinTaskDialog::require_elevation] was called with the id of a button that
+    /// the dialog does not display.
+    fn check_elevated_buttons(&self) -> crate::Result<()> {
+        match self
+            .elevated_buttons
+            .iter()
+            .find(|id| !self.has_button(**id))
+        {
+            Some(id) => Err(crate::Error::InvalidConfiguration(format!(
+                "button {id} requires elevation, but the dialog has no button with that id"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The strings of a [WinTaskDialog], encoded for the Windows api. The buffers must outlive
+/// the call to TaskDialogIndirect, since the configuration only holds pointers to them.
+#[cfg_attr(any(not(windows), feature = "mock"), allow(dead_code))]
+struct EncodedText {
+    /// The encoded body text.
+    content: Vec<u16>,
+    /// The encoded title bar text.
+    title: Option<Vec<u16>>,
+    /// The encoded main instruction.
+    instruction: Option<Vec<u16>>,
+    /// The encoded button labels, in the same order as the buttons.
+    buttons: Vec<Vec<u16>>,
+    /// The encoded radio button labels, in the same order as the radio buttons.
+    radio_buttons: Vec<Vec<u16>>,
+    /// The encoded label of the verification checkbox.
+    verification: Option<Vec<u16>>,
+    /// The encoded footer text.
+    footer: Option<Vec<u16>>,
+    /// The encoded hidden information.
+    expanded_info: Option<Vec<u16>>,
+    /// The encoded expander label shown while the information is hidden.
+    collapsed_label: Option<Vec<u16>>,
+    /// The encoded expander label shown while the information is visible.
+    expanded_label: Option<Vec<u16>>,
+}
+
+impl EncodedText {
+    /// Encodes all strings of the task dialog, failing if any of them is invalid.
+    fn new(dialog: &WinTaskDialog) -> crate::Result<Self> {
+        Ok(Self {
+            content: to_wide(&dialog.content)?,
+            title: dialog.title.as_deref().map(to_wide).transpose()?,
+            instruction: dialog.instruction.as_deref().map(to_wide).transpose()?,
+            buttons: dialog
+                .buttons
+                .iter()
+                .map(|button| to_wide(&button.text))
+                .collect::<crate::Result<Vec<_>>>()?,
+            radio_buttons: dialog
+                .radio_buttons
+                .iter()
+                .map(|radio| to_wide(&radio.label))
+                .collect::<crate::Result<Vec<_>>>()?,
+            verification: dialog.verification.as_deref().map(to_wide).transpose()?,
+            footer: dialog.footer.as_deref().map(to_wide).transpose()?,
+            expanded_info: dialog.expanded_info.as_deref().map(to_wide).transpose()?,
+            collapsed_label: dialog
+                .expander_labels
+                .as_ref()
+                .map(|(collapsed, _)| to_wide(collapsed))
+                .transpose()?,
+            expanded_label: dialog
+                .expander_labels
+                .as_ref()
+                .map(|(_, expanded)| to_wide(expanded))
+                .transpose()?,
+        })
+    }
+}
+
+/// Returns a pointer to an optional encoded string, or null if it is absent.
+#[cfg(all(windows, not(feature = "mock")))]
+fn optional_ptr(text: &Option<Vec<u16>>) -> PCWSTR {
+    text.as_ref()
+        .map(|text| PCWSTR::from_raw(text.as_ptr()))
+        .unwrap_or(PCWSTR::null())
+}
+
+/// Opens the target of a hyperlink with its default program.
+#[cfg(all(windows, not(feature = "mock")))]
+fn open_in_browser(href: &str) {
+    windows_targets::link!("shell32.dll" "system" fn ShellExecuteW(hwnd: HWND, lpoperation: PCWSTR, lpfile: PCWSTR, lpparameters: PCWSTR, lpdirectory: PCWSTR, nshowcmd: i32) -> isize);
+
+    // Links containing a nul character cannot be opened.
+    let Ok(file) = to_wide(href) else {
+        return;
+    };
+    let operation = to_wide("open").unwrap_or_default();
+
+    // ShellExecuteW displays its own error message if the target cannot be opened.
+    unsafe {
+        ShellExecuteW(
+            HWND::default(),
+            PCWSTR::from_raw(operation.as_ptr()),
+            PCWSTR::from_raw(file.as_ptr()),
+            PCWSTR::null(),
+            PCWSTR::null(),
+            SW_SHOWNORMAL.0,
+        );
+    }
+}
+
+/// Without Windows, or with the `mock` feature enabled, no dialog is displayed, so no
+/// hyperlink is ever clicked.
+#[cfg(any(not(windows), feature = "mock"))]
+fn open_in_browser(_href: &str) {}
+
+/// Loads the handle of the system icon matching an [Icon]. Unlike the task dialog icon
+/// identifiers, displaying an icon handle does not play a sound. Stock icons are shared
+/// and must not be destroyed.
+#[cfg(all(windows, not(feature = "mock")))]
+fn stock_icon(icon: Icon) -> Option<HICON> {
+    let name = match icon {
+        Icon::Exclamation | Icon::Warning => IDI_WARNING,
+        Icon::Information | Icon::Asterisk => IDI_INFORMATION,
+        Icon::Stop | Icon::Error | Icon::Hand => IDI_ERROR,
+        Icon::Shield => IDI_SHIELD,
+        #[cfg(feature = "deprecated")]
+        #[allow(deprecated)]
+        Icon::Question => return None,
+    };
+
+    unsafe { LoadIconW(None, name) }.ok()
+}
+
+/// What the callback of a task dialog has access to while the dialog is displayed.
+#[cfg(all(windows, not(feature = "mock")))]
+struct CallbackState<'a> {
+    /// The configuration of the dialog being displayed.
+    dialog: &'a WinTaskDialog,
+    /// Whether the dialog is only created to be measured. See [WinTaskDialog::measure].
+    measure: bool,
+    /// The size of the dialog window, recorded when measuring.
+    size: Cell<Option<crate::Result<(u32, u32)>>>,
+    /// Whether the dialog was closed because its dismiss flag was set.
+    dismissed: Cell<bool>,
+    /// Whether the user closed the dialog from its title bar, see [watch_close].
+    closed_via_x: Cell<bool>,
+}
+
+/// Receives the notifications that a task dialog sends while it is displayed. The reference
+/// data is a pointer to the [CallbackState] of the dialog, which outlives the dialog.
+#[cfg(all(windows, not(feature = "mock")))]
+unsafe extern "system" fn callback(
+    window: HWND,
+    notification: TASKDIALOG_NOTIFICATIONS,
+    _wparam: WPARAM,
+    lparam: LPARAM,
+    data: isize,
+) -> HRESULT {
+    let state = &*(data as *const CallbackState);
+    let dialog = state.dialog;
+
+    // The dialog has been laid out, but not displayed. Ending it now means that it never
+    // appears on screen.
+    if state.measure {
+        if notification == TDN_DIALOG_CONSTRUCTED {
+            let mut rect = RECT::default();
+            let size = GetWindowRect(window, &mut rect)
+                .map(|_| {
+                    let width = rect.right - rect.left;
+                    let height = rect.bottom - rect.top;
+                    (width.unsigned_abs(), height.unsigned_abs())
+                })
+                .map_err(|error| crate::Error::TaskDialogError(error.code()));
+            state.size.set(Some(size));
+            let _ = EndDialog(window, IDCANCEL.0 as isize);
+        }
+        return S_OK;
+    }
+
+    if notification == TDN_CREATED {
+        match dialog.monitor {
+            Some(index) => center_on_monitor(window, index),
+            // Dialogs with a parent are centered on it by Windows, see task_dialog_indirect.
+            None if dialog.center_on_parent && dialog.parent == HWND::default() => {
+                center_on_primary_monitor(window)
+            }
+            None => {}
+        }
+        if dialog.buttons_right {
+            align_buttons_right(window);
+        }
+        watch_close(window, &state.closed_via_x);
+        if dialog.no_activate {
+            let _ = ShowWindow(window, SW_SHOWNOACTIVATE);
+        }
+        if dialog.disable_close {
+            let menu = GetSystemMenu(window, false);
+            let _ = EnableMenuItem(menu, SC_CLOSE, MF_BYCOMMAND | MF_GRAYED);
+        }
+        for id in &dialog.elevated_buttons {
+            let message = TDM_SET_BUTTON_ELEVATION_REQUIRED_STATE.0 as u32;
+            SendMessageW(window, message, WPARAM(*id as usize), LPARAM(1));
+        }
+    }
+
+    if notification == TDN_DESTROYED {
+        unwatch_close(window);
+    }
+
+    if let Some(progress) = &dialog.progress {
+        if notification == TDN_CREATED {
+            progress.attach(window, dialog.marquee);
+        } else if notification == TDN_DESTROYED {
+            progress.detach();
+        }
+    }
+
+    if let Some(flag) = &dialog.dismiss {
+        let tick = notification == TDN_CREATED || notification == TDN_TIMER;
+        if tick && !state.dismissed.get() && flag.0.load(Ordering::Relaxed) {
+            // Clicking 'cancel' with TDM_CLICK_BUTTON has no effect on dialogs without that
+            // button, so the dialog is ended directly.
+            state.dismissed.set(true);
+            let _ = EndDialog(window, IDCANCEL.0 as isize);
+        }
+    }
+
+    if let Some(on_hyperlink) = &dialog.on_hyperlink {
+        if notification == TDN_HYPERLINK_CLICKED {
+            let href = PCWSTR::from_raw(lparam.0 as *const u16);
+            (*on_hyperlink.lock())(&String::from_utf16_lossy(href.as_wide()));
+        }
+    }
+
+    if let Some(on_help) = &dialog.on_help {
+        if notification == TDN_HELP {
+            (*on_help.lock())();
+        }
+    }
+
+    if dialog.keep_topmost && (notification == TDN_CREATED || notification == TDN_TIMER) {
+        make_topmost(window);
+    }
+
+    S_OK
+}
+
+/// Identifies the subclass installed by [watch_close] among those of the dialog window.
+#[cfg(all(windows, not(feature = "mock")))]
+const CLOSE_SUBCLASS_ID: usize = 1;
+
+/// The subclass procedure of a dialog window, see SetWindowSubclass.
+#[cfg(all(windows, not(feature = "mock")))]
+type SubclassProc =
+    Option<unsafe extern "system" fn(HWND, u32, WPARAM, LPARAM, usize, usize) -> LRESULT>;
+
+/// Subclasses the dialog window to record in `closed` when the user closes it from the
+/// title bar. The task dialog only reports a click on 'cancel' in that case, but the close
+/// button, the system menu and Alt+F4 all send the window a close command first. The flag
+/// must outlive the window, see [unwatch_close].
+#[cfg(all(windows, not(feature = "mock")))]
+unsafe fn watch_close(window: HWND, closed: &Cell<bool>) {
+    windows_targets::link!("comctl32.dll" "system" fn SetWindowSubclass(hwnd: HWND, pfnsubclass: SubclassProc, uidsubclass: usize, dwrefdata: usize) -> BOOL);
+
+    let data = closed as *const Cell<bool> as usize;
+    // Without the subclass, closing the dialog is reported as clicking 'cancel'.
+    let _ = SetWindowSubclass(window, Some(close_subclass), CLOSE_SUBCLASS_ID, data);
+}
+
+/// Removes the subclass installed by [watch_close] before the dialog window is destroyed.
+#[cfg(all(windows, not(feature = "mock")))]
+unsafe fn unwatch_close(window: HWND) {
+    windows_targets::link!("comctl32.dll" "system" fn RemoveWindowSubclass(hwnd: HWND, pfnsubclass: SubclassProc, uidsubclass: usize) -> BOOL);
+
+    let _ = RemoveWindowSubclass(window, Some(close_subclass), CLOSE_SUBCLASS_ID);
+}
+
+/// Receives the messages of a dialog window subclassed by [watch_close]. The reference
+/// data is a pointer to the flag that is set when the window receives a close command.
+#[cfg(all(windows, not(feature = "mock")))]
+unsafe extern "system" fn close_subclass(
+    window: HWND,
+    message: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+    _id: usize,
+    data: usize,
+) -> LRESULT {
+    windows_targets::link!("comctl32.dll" "system" fn DefSubclassProc(hwnd: HWND, umsg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT);
+
+    // The low four bits of the command are used internally by Windows.
+    if message == WM_SYSCOMMAND && (wparam.0 & 0xFFF0) as u32 == SC_CLOSE {
+        (*(data as *const Cell<bool>)).set(true);
+    }
+
+    DefSubclassProc(window, message, wparam, lparam)
+}
+
+/// The raw results of the TaskDialogIndirect api: the clicked button, the selected radio
+/// button and the state of the verification checkbox.
+#[cfg(all(windows, not(feature = "mock")))]
+type RawResponse = (i32, i32, BOOL);
+
+/// Invokes the TaskDialogIndirect api with the encoded strings of the task dialog.
+#[cfg(all(windows, not(feature = "mock")))]
+fn task_dialog_indirect(text: &EncodedText, state: &CallbackState) -> crate::Result<RawResponse> {
+    let dialog = state.dialog;
+
+    // The pointers borrow the encoded buffers, which outlive the call below.
+    let button_array = |ids: Vec<i32>, labels: &[Vec<u16>]| {
+        ids.into_iter()
+            .zip(labels)
+            .map(|(id, label)| TASKDIALOG_BUTTON {
+                nButtonID: id,
+                pszButtonText: PCWSTR::from_raw(label.as_ptr()),
+            })
+            .collect::<Vec<_>>()
+    };
+    let buttons = button_array(
+        dialog.buttons.iter().map(|button| button.id).collect(),
+        &text.buttons,
+    );
+    let radio_buttons = button_array(
+        dialog.radio_buttons.iter().map(|radio| radio.id).collect(),
+        &text.radio_buttons,
+    );
+
+    let system_icon = dialog.icon.map(PCWSTR::from).unwrap_or(PCWSTR::null());
+
+    // The footer icon is only meaningful alongside footer text.
+    let footer_icon = match text.footer {
+        Some(_) => dialog
+            .footer_icon
+            .map(PCWSTR::from)
+            .unwrap_or(PCWSTR::null()),
+        None => PCWSTR::null(),
+    };
+
+    let silent_icon = match dialog.silent {
+        true => dialog.icon.and_then(stock_icon),
+        false => None,
+    };
+
+    let (main_icon, icon_flags) = match dialog.custom_icon.or(silent_icon) {
+        Some(icon) => (TASKDIALOGCONFIG_0 { hMainIcon: icon }, TDF_USE_HICON_MAIN),
+        None => (
+            TASKDIALOGCONFIG_0 {
+                pszMainIcon: system_icon,
+            },
+            TASKDIALOG_FLAGS::default(),
+        ),
+    };
+
+    let link_flags = match dialog.command_links {
+        true => TDF_USE_COMMAND_LINKS,
+        false => TASKDIALOG_FLAGS::default(),
+    };
+
+    let hyperlink_flags = match dialog.hyperlinks {
+        true => TDF_ENABLE_HYPERLINKS,
+        false => TASKDIALOG_FLAGS::default(),
+    };
+
+    let expanded_flags = match dialog.start_expanded {
+        true => TDF_EXPANDED_BY_DEFAULT,
+        false => TASKDIALOG_FLAGS::default(),
+    };
+
+    let timer_flags = match dialog.keep_topmost || dialog.dismiss.is_some() {
+        true => TDF_CALLBACK_TIMER,
+        false => TASKDIALOG_FLAGS::default(),
+    };
+
+    let layout_flags = match dialog.language.is_some_and(is_rtl_language) {
+        true => TDF_RTL_LAYOUT,
+        false => TASKDIALOG_FLAGS::default(),
+    };
+
+    let position_flags = match dialog.center_on_parent && dialog.parent != HWND::default() {
+        true => TDF_POSITION_RELATIVE_TO_WINDOW,
+        false => TASKDIALOG_FLAGS::default(),
+    };
+
+    let radio_flags = match dialog.no_default_radio {
+        true => TDF_NO_DEFAULT_RADIO_BUTTON,
+        false => TASKDIALOG_FLAGS::default(),
+    };
+
+    let progress_flags = match (&dialog.progress, dialog.marquee) {
+        (Some(_), false) => TDF_SHOW_PROGRESS_BAR,
+        (Some(_), true) => TDF_SHOW_MARQUEE_PROGRESS_BAR,
+        (None, _) => TASKDIALOG_FLAGS::default(),
+    };
+
+    let config = TASKDIALOGCONFIG {
+        cbSize: std::mem::size_of::<TASKDIALOGCONFIG>() as u32,
+        hwndParent: dialog.parent,
+        pszWindowTitle: optional_ptr(&text.title),
+        dwFlags: icon_flags
+            | link_flags
+            | hyperlink_flags
+            | expanded_flags
+            | timer_flags
+            | progress_flags
+            | position_flags
+            | layout_flags
+            | radio_flags,
+        Anonymous1: main_icon,
+        pszMainInstruction: optional_ptr(&text.instruction),
+        pszContent: PCWSTR::from_raw(text.content.as_ptr()),
+        dwCommonButtons: dialog.common_buttons,
+        pfCallback: Some(callback),
+        lpCallbackData: state as *const CallbackState as isize,
+        cButtons: buttons.len() as u32,
+        pButtons: buttons.as_ptr(),
+        nDefaultButton: dialog.default_button.unwrap_or_default(),
+        cRadioButtons: radio_buttons.len() as u32,
+        pRadioButtons: radio_buttons.as_ptr(),
+        nDefaultRadioButton: dialog.default_radio.unwrap_or_default(),
+        pszVerificationText: optional_ptr(&text.verification),
+        pszFooter: optional_ptr(&text.footer),
+        pszExpandedInformation: optional_ptr(&text.expanded_info),
+        pszCollapsedControlText: optional_ptr(&text.collapsed_label),
+        pszExpandedControlText: optional_ptr(&text.expanded_label),
+        Anonymous2: TASKDIALOGCONFIG_1 {
+            pszFooterIcon: footer_icon,
+        },
+        ..Default::default()
+    };
+
+    let mut button = 0;
+    let mut radio = 0;
+    let mut verification_checked = BOOL::default();
+    unsafe {
+        TaskDialogIndirect(
+            &config,
+            Some(&mut button),
+            Some(&mut radio),
+            Some(&mut verification_checked),
+        )
+    }
+    .map_err(|error| crate::Error::TaskDialogError(error.code()))?;
+
+    Ok((button, radio, verification_checked))
+}
+
+/// Displays the task dialog and collects the user's response.
+#[cfg(all(windows, not(feature = "mock")))]
+fn task_dialog(dialog: &WinTaskDialog, text: &EncodedText) -> crate::Result<TaskDialogResponse> {
+    let state = CallbackState {
+        dialog,
+        measure: false,
+        size: Cell::new(None),
+        dismissed: Cell::new(false),
+        closed_via_x: Cell::new(false),
+    };
+    let (mut button, radio, verification_checked) = task_dialog_indirect(text, &state)?;
+
+    // Finishing the operation closes the dialog by clicking 'cancel' on the user's behalf.
+    if dialog
+        .progress
+        .as_ref()
+        .is_some_and(ProgressController::is_finished)
+    {
+        button = IDOK.0;
+    }
+
+    Ok(TaskDialogResponse {
+        response: button,
+        verification_checked: verification_checked.as_bool(),
+        radio: match dialog.radio_buttons.iter().any(|option| option.id == radio) {
+            true => Some(radio),
+            false => None,
+        },
+        dismissed: state.dismissed.get(),
+        // The close command may have been ignored, for example if closing is disabled.
+        closed_via_x: state.closed_via_x.get() && button == IDCANCEL.0 && !state.dismissed.get(),
+    })
+}
+
+/// Creates the task dialog without displaying it, and reports the size of its window.
+#[cfg(all(windows, not(feature = "mock")))]
+fn measure_dialog(dialog: &WinTaskDialog, text: &EncodedText) -> crate::Result<(u32, u32)> {
+    let state = CallbackState {
+        dialog,
+        measure: true,
+        size: Cell::new(None),
+        dismissed: Cell::new(false),
+        closed_via_x: Cell::new(false),
+    };
+    // The dialog is ended by the callback, so there is no response to report.
+    let _ = task_dialog_indirect(text, &state)?;
+
+    state
+        .size
+        .take()
+        .unwrap_or(Err(crate::Error::TaskDialogError(E_FAIL)))
+}
+
+/// Without Windows, or with the `mock` feature enabled, no window is ever created, so there
+/// is nothing to measure and measuring always fails with [crate::Error::Unsupported].
+#[cfg(any(not(windows), feature = "mock"))]
+fn measure_dialog(_dialog: &WinTaskDialog, _text: &EncodedText) -> crate::Result<(u32, u32)> {
+    Err(crate::Error::Unsupported)
+}
+
+/// Task dialogs are only available on Windows. On other platforms the dialog is fully
+/// configurable, but showing it always fails with [crate::Error::Unsupported].
+#[cfg(all(not(windows), not(feature = "mock")))]
+fn task_dialog(_dialog: &WinTaskDialog, _text: &EncodedText) -> crate::Result<TaskDialogResponse> {
+    Err(crate::Error::Unsupported)
+}
+
+/// With the `mock` feature enabled, no task dialog is displayed. Instead, the response
+/// injected with [crate::set_mock_response] is returned as the id of the clicked button.
+#[cfg(feature = "mock")]
+fn task_dialog(dialog: &WinTaskDialog, _text: &EncodedText) -> crate::Result<TaskDialogResponse> {
+    let dismissed = dialog
+        .dismiss
+        .as_ref()
+        .is_some_and(|flag| flag.0.load(Ordering::Relaxed));
+    if dismissed {
+        return Ok(TaskDialogResponse {
+            response: IDCANCEL.0,
+            verification_checked: false,
+            radio: None,
+            dismissed,
+            closed_via_x: false,
+        });
+    }
+
+    crate::mock::mock_response().map(|response| TaskDialogResponse {
+        response: response.0,
+        verification_checked: false,
+        radio: None,
+        dismissed,
+        closed_via_x: false,
+    })
+}